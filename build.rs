@@ -0,0 +1,178 @@
+// Falcon için FFT ve NTT kök tablolarının üretimi
+//
+// The tables follow the ordering used by fft.rs and ntt.rs: the roots of
+// x^n + 1 are stored so that w[2i]^2 is the i-th root of x^(n/2) + 1 and
+// w[2i + 1] = -w[2i]. In C the square root taken is the principal one, in
+// Z_q it is the one below q / 2.
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const Q: u64 = 12289;
+const MAX_N: usize = 1024;
+
+/// Fractional bits of the fixed-point numbers cos and sin are evaluated with
+const FRAC: u32 = 124;
+/// floor(pi * 2^124)
+const PI_FIXED: u128 = 0x3243F6A8885A308D313198A2E0370734;
+
+/// Product of two fixed-point numbers below 4, rounded down
+fn mul_fixed(a: u128, b: u128) -> u128 {
+    let mask = (1u128 << 64) - 1;
+    let (a1, a0, b1, b0) = (a >> 64, a & mask, b >> 64, b & mask);
+    let mid = a1 * b0 + a0 * b1;
+    let lo = (a0 * b0).wrapping_add(mid << 64);
+    let carry = (lo < (mid << 64)) as u128;
+    let hi = a1 * b1 + (mid >> 64) + carry;
+    (hi << (128 - FRAC)) | (lo >> FRAC)
+}
+
+/// cos and sin of pi * j / n in fixed point, for 0 <= j < n / 2 and n a
+/// power of two, from their Taylor series
+fn cos_sin_fixed(j: u128, n: u128) -> (u128, u128) {
+    let s = n.trailing_zeros();
+    let t = (PI_FIXED >> s) * j + (((PI_FIXED & ((1 << s) - 1)) * j) >> s);
+    let (mut c, mut sn) = (0i128, 0i128);
+    // term = t^k / k!
+    let mut term = 1u128 << FRAC;
+    let mut k = 0;
+    while term != 0 {
+        match k % 4 {
+            0 => c += term as i128,
+            1 => sn += term as i128,
+            2 => c -= term as i128,
+            _ => sn -= term as i128,
+        }
+        k += 1;
+        term = mul_fixed(term, t) / k;
+    }
+    (c as u128, sn as u128)
+}
+
+/// Rounds a non-negative fixed-point number to the nearest f64, ties to even
+fn fixed_to_f64(x: u128) -> f64 {
+    if x == 0 {
+        return 0.0;
+    }
+    let len = 128 - x.leading_zeros();
+    let mut e = len as i64 - 1 - FRAC as i64;
+    let mut m = if len > 53 {
+        let shift = len - 53;
+        let (m, rest, half) = (x >> shift, x & ((1 << shift) - 1), 1u128 << (shift - 1));
+        if rest > half || (rest == half && m & 1 == 1) { m + 1 } else { m }
+    } else {
+        x << (53 - len)
+    };
+    if m == 1 << 53 {
+        m >>= 1;
+        e += 1;
+    }
+    f64::from_bits((((e + 1023) as u64) << 52) | (m as u64 & ((1 << 52) - 1)))
+}
+
+/// exp(i * pi * k / n), computed from a first-quadrant angle so that the
+/// symmetries of the table are exact
+///
+/// cos and sin are evaluated with integer arithmetic and correctly rounded,
+/// so that the table does not depend on the libm of the build host.
+fn cis(k: i64, n: i64) -> (f64, f64) {
+    let quarter = n / 2;
+    let k = k.rem_euclid(2 * n);
+    let (c, s) = cos_sin_fixed((k % quarter) as u128, n as u128);
+    let (c, s) = (fixed_to_f64(c), fixed_to_f64(s));
+    let (re, im) = match k / quarter {
+        0 => (c, s),
+        1 => (-s, c),
+        2 => (-c, -s),
+        _ => (s, -c),
+    };
+    (re + 0.0, im + 0.0)
+}
+
+fn pow_mod(mut a: u64, mut e: u64) -> u64 {
+    let mut r = 1;
+    a %= Q;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = r * a % Q;
+        }
+        a = a * a % Q;
+        e >>= 1;
+    }
+    r
+}
+
+/// Square root of a mod q lying below q / 2
+fn sqrt_mod(a: u64) -> u64 {
+    (1..=Q / 2).find(|x| x * x % Q == a).expect("no square root mod q")
+}
+
+fn fft_tables() -> String {
+    let mut out = String::new();
+    // Exponents k of the roots exp(i * pi * k / n) of x^n + 1
+    let mut exps: Vec<i64> = vec![1, -1];
+    let mut n = 2;
+    while n <= MAX_N {
+        writeln!(out, "/// Roots of x^{} + 1", n).unwrap();
+        writeln!(out, "static PHI{}_ROOTS: [Complex64; {}] = [", 2 * n, n).unwrap();
+        for &k in &exps {
+            let (re, im) = cis(k, n as i64);
+            writeln!(
+                out,
+                "    Complex64::new(f64::from_bits({:#018x}), f64::from_bits({:#018x})),",
+                re.to_bits(),
+                im.to_bits()
+            )
+            .unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+        let m = 2 * n as i64;
+        exps = exps
+            .iter()
+            .flat_map(|&k| [k, if k > 0 { k - m } else { k + m }])
+            .collect();
+        n *= 2;
+    }
+    out
+}
+
+fn ntt_tables() -> String {
+    let mut out = String::new();
+    let mut roots: Vec<u64> = vec![1479, Q - 1479];
+    let mut n = 2;
+    while n <= MAX_N {
+        writeln!(out, "/// Roots of x^{} + 1 mod q", n).unwrap();
+        writeln!(out, "pub static PHI{}_ROOTS_ZQ: [u32; {}] = [", 2 * n, n).unwrap();
+        for chunk in roots.chunks(16) {
+            let line: Vec<String> = chunk.iter().map(|r| r.to_string()).collect();
+            writeln!(out, "    {},", line.join(", ")).unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+        roots = roots
+            .iter()
+            .flat_map(|&r| {
+                let s = sqrt_mod(r);
+                [s, Q - s]
+            })
+            .collect();
+        n *= 2;
+    }
+    writeln!(out, "/// a^(-1) mod q listesi, a = 0, 1, ..., q - 1 için").unwrap();
+    writeln!(out, "pub static INV_MOD_Q: [u32; {}] = [", Q).unwrap();
+    let inv: Vec<u64> = (0..Q).map(|a| pow_mod(a, Q - 2)).collect();
+    for chunk in inv.chunks(16) {
+        let line: Vec<String> = chunk.iter().map(|r| r.to_string()).collect();
+        writeln!(out, "    {},", line.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dir = Path::new(&out_dir);
+    fs::write(dir.join("fft_roots.rs"), fft_tables()).unwrap();
+    fs::write(dir.join("ntt_roots.rs"), ntt_tables()).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -1,2105 +1,11 @@
+#![allow(clippy::approx_constant)]
+
 use num_complex::Complex64;
 
-// Cyclotomic polinomların kökleri (NTT için)
-// Bu sabitler scripts/generate_constants.sage kullanılarak hesaplanmıştır.
+// Cyclotomic polinomların kökleri (FFT için)
+// Bu tablolar derleme sırasında build.rs tarafından üretilir.
 // fft_short ile kullanılırsa, her phi*_roots tablosunun sadece ilk yarısı gerekli.
-
-/// φ₄ = x² + 1 polinomunun kökleri
-/// İkinci kök, birincinin eşlenikidir.
-static PHI4_ROOTS: [Complex64; 2] = [
-    Complex64::new(0.0, 1.00000000000000),
-    Complex64::new(0.0, -1.00000000000000),
-];
-
-/// φ₈ = x⁴ + 1 polinomunun kökleri
-/// Köklerin ikinci yarısı, ilk yarının eşlenikleridir.
-/// (2*i + 1) indeksindeki kök, (2*i) indeksindeki kökün negatifidir.
-static PHI8_ROOTS: [Complex64; 4] = [
-    Complex64::new(0.707106781186548, 0.707106781186547),
-    Complex64::new(-0.707106781186548, -0.707106781186547),
-    Complex64::new(0.707106781186548, -0.707106781186547),
-    Complex64::new(-0.707106781186548, 0.707106781186547),
-];
-
-/// φ₁₆ = x⁸ + 1 polinomunun kökleri
-static PHI16_ROOTS: [Complex64; 8] = [
-    Complex64::new(0.923879532511287, 0.382683432365090),
-    Complex64::new(-0.923879532511287, -0.382683432365090),
-    Complex64::new(0.382683432365090, -0.923879532511287),
-    Complex64::new(-0.382683432365090, 0.923879532511287),
-    Complex64::new(0.923879532511287, -0.382683432365090),
-    Complex64::new(-0.923879532511287, 0.382683432365090),
-    Complex64::new(0.382683432365090, 0.923879532511287),
-    Complex64::new(-0.382683432365090, -0.923879532511287),
-];
-
-/// φ₃₂ = x¹⁶ + 1 polinomunun kökleri
-static PHI32_ROOTS: [Complex64; 16] = [
-    Complex64::new(0.980785280403230, 0.195090322016128),
-    Complex64::new(-0.980785280403230, -0.195090322016128),
-    Complex64::new(0.195090322016128, -0.980785280403230),
-    Complex64::new(-0.195090322016128, 0.980785280403230),
-    Complex64::new(0.831469612302545, -0.555570233019602),
-    Complex64::new(-0.831469612302545, 0.555570233019602),
-    Complex64::new(0.555570233019602, 0.831469612302545),
-    Complex64::new(-0.555570233019602, -0.831469612302545),
-    Complex64::new(0.980785280403230, -0.195090322016128),
-    Complex64::new(-0.980785280403230, 0.195090322016128),
-    Complex64::new(0.195090322016128, 0.980785280403230),
-    Complex64::new(-0.195090322016128, -0.980785280403230),
-    Complex64::new(0.831469612302545, 0.555570233019602),
-    Complex64::new(-0.831469612302545, -0.555570233019602),
-    Complex64::new(0.555570233019602, -0.831469612302545),
-    Complex64::new(-0.555570233019602, 0.831469612302545),
-];
-
-/// φ₆₄ = x³² + 1 polinomunun kökleri
-static PHI64_ROOTS: [Complex64; 32] = [
-    Complex64::new(0.995184726672197, 0.0980171403295606),
-    Complex64::new(-0.995184726672197, -0.0980171403295606),
-    Complex64::new(0.0980171403295606, -0.995184726672197),
-    Complex64::new(-0.0980171403295606, 0.995184726672197),
-    Complex64::new(0.773010453362737, -0.634393284163646),
-    Complex64::new(-0.773010453362737, 0.634393284163646),
-    Complex64::new(0.634393284163645, 0.773010453362737),
-    Complex64::new(-0.634393284163645, -0.773010453362737),
-    Complex64::new(0.956940335732209, -0.290284677254462),
-    Complex64::new(-0.956940335732209, 0.290284677254462),
-    Complex64::new(0.290284677254462, 0.956940335732209),
-    Complex64::new(-0.290284677254462, -0.956940335732209),
-    Complex64::new(0.881921264348355, 0.471396736825998),
-    Complex64::new(-0.881921264348355, -0.471396736825998),
-    Complex64::new(0.471396736825998, -0.881921264348355),
-    Complex64::new(-0.471396736825998, 0.881921264348355),
-    Complex64::new(0.995184726672197, -0.0980171403295606),
-    Complex64::new(-0.995184726672197, 0.0980171403295606),
-    Complex64::new(0.0980171403295606, 0.995184726672197),
-    Complex64::new(-0.0980171403295606, -0.995184726672197),
-    Complex64::new(0.773010453362737, 0.634393284163646),
-    Complex64::new(-0.773010453362737, -0.634393284163646),
-    Complex64::new(0.634393284163645, -0.773010453362737),
-    Complex64::new(-0.634393284163645, 0.773010453362737),
-    Complex64::new(0.956940335732209, 0.290284677254462),
-    Complex64::new(-0.956940335732209, -0.290284677254462),
-    Complex64::new(0.290284677254462, -0.956940335732209),
-    Complex64::new(-0.290284677254462, 0.956940335732209),
-    Complex64::new(0.881921264348355, -0.471396736825998),
-    Complex64::new(-0.881921264348355, 0.471396736825998),
-    Complex64::new(0.471396736825998, 0.881921264348355),
-    Complex64::new(-0.471396736825998, -0.881921264348355),
-];
-
-/// φ₁₂₈ = x⁶⁴ + 1 polinomunun kökleri
-static PHI128_ROOTS: [Complex64; 64] = [
-    Complex64::new(0.998795456205172, 0.0490676743274180),
-    Complex64::new(-0.998795456205172, -0.0490676743274180),
-    Complex64::new(0.0490676743274180, -0.998795456205172),
-    Complex64::new(-0.0490676743274180, 0.998795456205172),
-    Complex64::new(0.740951125354959, -0.671558954847018),
-    Complex64::new(-0.740951125354959, 0.671558954847018),
-    Complex64::new(0.671558954847018, 0.740951125354959),
-    Complex64::new(-0.671558954847018, -0.740951125354959),
-    Complex64::new(0.941544065183021, -0.336889853392220),
-    Complex64::new(-0.941544065183021, 0.336889853392220),
-    Complex64::new(0.336889853392220, 0.941544065183021),
-    Complex64::new(-0.336889853392220, -0.941544065183021),
-    Complex64::new(0.903989293123443, 0.427555093430282),
-    Complex64::new(-0.903989293123443, -0.427555093430282),
-    Complex64::new(0.427555093430282, -0.903989293123443),
-    Complex64::new(-0.427555093430282, 0.903989293123443),
-    Complex64::new(0.989176509964781, -0.146730474455362),
-    Complex64::new(-0.989176509964781, 0.146730474455362),
-    Complex64::new(0.146730474455362, 0.989176509964781),
-    Complex64::new(-0.146730474455362, -0.989176509964781),
-    Complex64::new(0.803207531480645, 0.595699304492433),
-    Complex64::new(-0.803207531480645, -0.595699304492433),
-    Complex64::new(0.595699304492433, -0.803207531480645),
-    Complex64::new(-0.595699304492433, 0.803207531480645),
-    Complex64::new(0.970031253194544, 0.242980179903264),
-    Complex64::new(-0.970031253194544, -0.242980179903264),
-    Complex64::new(0.242980179903264, -0.970031253194544),
-    Complex64::new(-0.242980179903264, 0.970031253194544),
-    Complex64::new(0.857728610000272, -0.514102744193222),
-    Complex64::new(-0.857728610000272, 0.514102744193222),
-    Complex64::new(0.514102744193222, 0.857728610000272),
-    Complex64::new(-0.514102744193222, -0.857728610000272),
-    Complex64::new(0.998795456205172, -0.0490676743274180),
-    Complex64::new(-0.998795456205172, 0.0490676743274180),
-    Complex64::new(0.0490676743274180, 0.998795456205172),
-    Complex64::new(-0.0490676743274180, -0.998795456205172),
-    Complex64::new(0.740951125354959, 0.671558954847018),
-    Complex64::new(-0.740951125354959, -0.671558954847018),
-    Complex64::new(0.671558954847018, -0.740951125354959),
-    Complex64::new(-0.671558954847018, 0.740951125354959),
-    Complex64::new(0.941544065183021, 0.336889853392220),
-    Complex64::new(-0.941544065183021, -0.336889853392220),
-    Complex64::new(0.336889853392220, -0.941544065183021),
-    Complex64::new(-0.336889853392220, 0.941544065183021),
-    Complex64::new(0.903989293123443, -0.427555093430282),
-    Complex64::new(-0.903989293123443, 0.427555093430282),
-    Complex64::new(0.427555093430282, 0.903989293123443),
-    Complex64::new(-0.427555093430282, -0.903989293123443),
-    Complex64::new(0.989176509964781, 0.146730474455362),
-    Complex64::new(-0.989176509964781, -0.146730474455362),
-    Complex64::new(0.146730474455362, -0.989176509964781),
-    Complex64::new(-0.146730474455362, 0.989176509964781),
-    Complex64::new(0.803207531480645, -0.595699304492433),
-    Complex64::new(-0.803207531480645, 0.595699304492433),
-    Complex64::new(0.595699304492433, 0.803207531480645),
-    Complex64::new(-0.595699304492433, -0.803207531480645),
-    Complex64::new(0.970031253194544, -0.242980179903264),
-    Complex64::new(-0.970031253194544, 0.242980179903264),
-    Complex64::new(0.242980179903264, 0.970031253194544),
-    Complex64::new(-0.242980179903264, -0.970031253194544),
-    Complex64::new(0.857728610000272, 0.514102744193222),
-    Complex64::new(-0.857728610000272, -0.514102744193222),
-    Complex64::new(0.514102744193222, -0.857728610000272),
-    Complex64::new(-0.514102744193222, 0.857728610000272),
-];
-
-/// φ₂₅₆ = x¹²⁸ + 1 polinomunun kökleri
-static PHI256_ROOTS: [Complex64; 128] = [
-    Complex64::new(0.999698818696204, 0.0245412285229123),
-    Complex64::new(-0.999698818696204, -0.0245412285229123),
-    Complex64::new(0.0245412285229123, -0.999698818696204),
-    Complex64::new(-0.0245412285229123, 0.999698818696204),
-    Complex64::new(0.724247082951467, -0.689540544737067),
-    Complex64::new(-0.724247082951467, 0.689540544737067),
-    Complex64::new(0.689540544737067, 0.724247082951467),
-    Complex64::new(-0.689540544737067, -0.724247082951467),
-    Complex64::new(0.932992798834739, -0.359895036534988),
-    Complex64::new(-0.932992798834739, 0.359895036534988),
-    Complex64::new(0.359895036534988, 0.932992798834739),
-    Complex64::new(-0.359895036534988, -0.932992798834739),
-    Complex64::new(0.914209755703531, 0.405241314004990),
-    Complex64::new(-0.914209755703531, -0.405241314004990),
-    Complex64::new(0.405241314004990, -0.914209755703531),
-    Complex64::new(-0.405241314004990, 0.914209755703531),
-    Complex64::new(0.985277642388941, -0.170961888760301),
-    Complex64::new(-0.985277642388941, 0.170961888760301),
-    Complex64::new(0.170961888760301, 0.985277642388941),
-    Complex64::new(-0.170961888760301, -0.985277642388941),
-    Complex64::new(0.817584813151584, 0.575808191417845),
-    Complex64::new(-0.817584813151584, -0.575808191417845),
-    Complex64::new(0.575808191417845, -0.817584813151584),
-    Complex64::new(-0.575808191417845, 0.817584813151584),
-    Complex64::new(0.975702130038529, 0.219101240156870),
-    Complex64::new(-0.975702130038529, -0.219101240156870),
-    Complex64::new(0.219101240156870, -0.975702130038529),
-    Complex64::new(-0.219101240156870, 0.975702130038529),
-    Complex64::new(0.844853565249707, -0.534997619887097),
-    Complex64::new(-0.844853565249707, 0.534997619887097),
-    Complex64::new(0.534997619887097, 0.844853565249707),
-    Complex64::new(-0.534997619887097, -0.844853565249707),
-    Complex64::new(0.997290456678690, -0.0735645635996674),
-    Complex64::new(-0.997290456678690, 0.0735645635996674),
-    Complex64::new(0.0735645635996674, 0.997290456678690),
-    Complex64::new(-0.0735645635996674, -0.997290456678690),
-    Complex64::new(0.757208846506485, 0.653172842953777),
-    Complex64::new(-0.757208846506485, -0.653172842953777),
-    Complex64::new(0.653172842953777, -0.757208846506485),
-    Complex64::new(-0.653172842953777, 0.757208846506485),
-    Complex64::new(0.949528180593037, 0.313681740398892),
-    Complex64::new(-0.949528180593037, -0.313681740398892),
-    Complex64::new(0.313681740398892, -0.949528180593037),
-    Complex64::new(-0.313681740398892, 0.949528180593037),
-    Complex64::new(0.893224301195515, -0.449611329654607),
-    Complex64::new(-0.893224301195515, 0.449611329654607),
-    Complex64::new(0.449611329654607, 0.893224301195515),
-    Complex64::new(-0.449611329654607, -0.893224301195515),
-    Complex64::new(0.992479534598710, 0.122410675199216),
-    Complex64::new(-0.992479534598710, -0.122410675199216),
-    Complex64::new(0.122410675199216, -0.992479534598710),
-    Complex64::new(-0.122410675199216, 0.992479534598710),
-    Complex64::new(0.788346427626606, -0.615231590580627),
-    Complex64::new(-0.788346427626606, 0.615231590580627),
-    Complex64::new(0.615231590580627, 0.788346427626606),
-    Complex64::new(-0.615231590580627, -0.788346427626606),
-    Complex64::new(0.963776065795440, -0.266712757474898),
-    Complex64::new(-0.963776065795440, 0.266712757474898),
-    Complex64::new(0.266712757474898, 0.963776065795440),
-    Complex64::new(-0.266712757474898, -0.963776065795440),
-    Complex64::new(0.870086991108711, 0.492898192229784),
-    Complex64::new(-0.870086991108711, -0.492898192229784),
-    Complex64::new(0.492898192229784, -0.870086991108711),
-    Complex64::new(-0.492898192229784, 0.870086991108711),
-    Complex64::new(0.999698818696204, -0.0245412285229123),
-    Complex64::new(-0.999698818696204, 0.0245412285229123),
-    Complex64::new(0.0245412285229123, 0.999698818696204),
-    Complex64::new(-0.0245412285229123, -0.999698818696204),
-    Complex64::new(0.724247082951467, 0.689540544737067),
-    Complex64::new(-0.724247082951467, -0.689540544737067),
-    Complex64::new(0.689540544737067, -0.724247082951467),
-    Complex64::new(-0.689540544737067, 0.724247082951467),
-    Complex64::new(0.932992798834739, 0.359895036534988),
-    Complex64::new(-0.932992798834739, -0.359895036534988),
-    Complex64::new(0.359895036534988, -0.932992798834739),
-    Complex64::new(-0.359895036534988, 0.932992798834739),
-    Complex64::new(0.914209755703531, -0.405241314004990),
-    Complex64::new(-0.914209755703531, 0.405241314004990),
-    Complex64::new(0.405241314004990, 0.914209755703531),
-    Complex64::new(-0.405241314004990, -0.914209755703531),
-    Complex64::new(0.985277642388941, 0.170961888760301),
-    Complex64::new(-0.985277642388941, -0.170961888760301),
-    Complex64::new(0.170961888760301, -0.985277642388941),
-    Complex64::new(-0.170961888760301, 0.985277642388941),
-    Complex64::new(0.817584813151584, -0.575808191417845),
-    Complex64::new(-0.817584813151584, 0.575808191417845),
-    Complex64::new(0.575808191417845, 0.817584813151584),
-    Complex64::new(-0.575808191417845, -0.817584813151584),
-    Complex64::new(0.975702130038529, -0.219101240156870),
-    Complex64::new(-0.975702130038529, 0.219101240156870),
-    Complex64::new(0.219101240156870, 0.975702130038529),
-    Complex64::new(-0.219101240156870, -0.975702130038529),
-    Complex64::new(0.844853565249707, 0.534997619887097),
-    Complex64::new(-0.844853565249707, -0.534997619887097),
-    Complex64::new(0.534997619887097, -0.844853565249707),
-    Complex64::new(-0.534997619887097, 0.844853565249707),
-    Complex64::new(0.997290456678690, 0.0735645635996674),
-    Complex64::new(-0.997290456678690, -0.0735645635996674),
-    Complex64::new(0.0735645635996674, -0.997290456678690),
-    Complex64::new(-0.0735645635996674, 0.997290456678690),
-    Complex64::new(0.757208846506485, -0.653172842953777),
-    Complex64::new(-0.757208846506485, 0.653172842953777),
-    Complex64::new(0.653172842953777, 0.757208846506485),
-    Complex64::new(-0.653172842953777, -0.757208846506485),
-    Complex64::new(0.949528180593037, -0.313681740398892),
-    Complex64::new(-0.949528180593037, 0.313681740398892),
-    Complex64::new(0.313681740398892, 0.949528180593037),
-    Complex64::new(-0.313681740398892, -0.949528180593037),
-    Complex64::new(0.893224301195515, 0.449611329654607),
-    Complex64::new(-0.893224301195515, -0.449611329654607),
-    Complex64::new(0.449611329654607, -0.893224301195515),
-    Complex64::new(-0.449611329654607, 0.893224301195515),
-    Complex64::new(0.992479534598710, -0.122410675199216),
-    Complex64::new(-0.992479534598710, 0.122410675199216),
-    Complex64::new(0.122410675199216, 0.992479534598710),
-    Complex64::new(-0.122410675199216, -0.992479534598710),
-    Complex64::new(0.788346427626606, 0.615231590580627),
-    Complex64::new(-0.788346427626606, -0.615231590580627),
-    Complex64::new(0.615231590580627, -0.788346427626606),
-    Complex64::new(-0.615231590580627, 0.788346427626606),
-    Complex64::new(0.963776065795440, 0.266712757474898),
-    Complex64::new(-0.963776065795440, -0.266712757474898),
-    Complex64::new(0.266712757474898, -0.963776065795440),
-    Complex64::new(-0.266712757474898, 0.963776065795440),
-    Complex64::new(0.870086991108711, -0.492898192229784),
-    Complex64::new(-0.870086991108711, 0.492898192229784),
-    Complex64::new(0.492898192229784, 0.870086991108711),
-    Complex64::new(-0.492898192229784, -0.870086991108711),
-];
-
-/// φ₅₁₂ = x²⁵⁶ + 1 polinomunun kökleri (şimdilik 256 elemanlı)
-/// İkinci yarı, ilk yarının eşlenikleridir.
-/// (2*i + 1) indeksindeki kök, (2*i) indeksindeki kökün negatifidir.
-static PHI512_ROOTS: [Complex64; 256] = [
-    Complex64::new(0.999924701839145, 0.0122715382857199),
-    Complex64::new(-0.999924701839145, -0.0122715382857199),
-    Complex64::new(0.0122715382857199, -0.999924701839145),
-    Complex64::new(-0.0122715382857199, 0.999924701839145),
-    Complex64::new(0.715730825283819, -0.698376249408973),
-    Complex64::new(-0.715730825283819, 0.698376249408973),
-    Complex64::new(0.698376249408973, 0.715730825283819),
-    Complex64::new(-0.698376249408973, -0.715730825283819),
-    Complex64::new(0.928506080473216, -0.371317193951837),
-    Complex64::new(-0.928506080473216, 0.371317193951837),
-    Complex64::new(0.371317193951837, 0.928506080473216),
-    Complex64::new(-0.371317193951837, -0.928506080473216),
-    Complex64::new(0.919113851690058, 0.393992040061048),
-    Complex64::new(-0.919113851690058, -0.393992040061048),
-    Complex64::new(0.393992040061048, -0.919113851690058),
-    Complex64::new(-0.393992040061048, 0.919113851690058),
-    Complex64::new(0.983105487431216, -0.183039887955141),
-    Complex64::new(-0.983105487431216, 0.183039887955141),
-    Complex64::new(0.183039887955141, 0.983105487431216),
-    Complex64::new(-0.183039887955141, -0.983105487431216),
-    Complex64::new(0.824589302785025, 0.565731810783613),
-    Complex64::new(-0.824589302785025, -0.565731810783613),
-    Complex64::new(0.565731810783613, -0.824589302785025),
-    Complex64::new(-0.565731810783613, 0.824589302785025),
-    Complex64::new(0.978317370719628, 0.207111376192219),
-    Complex64::new(-0.978317370719628, -0.207111376192219),
-    Complex64::new(0.207111376192219, -0.978317370719628),
-    Complex64::new(-0.207111376192219, 0.978317370719628),
-    Complex64::new(0.838224705554838, -0.545324988422046),
-    Complex64::new(-0.838224705554838, 0.545324988422046),
-    Complex64::new(0.545324988422046, 0.838224705554838),
-    Complex64::new(-0.545324988422046, -0.838224705554838),
-    Complex64::new(0.996312612182778, -0.0857973123444399),
-    Complex64::new(-0.996312612182778, 0.0857973123444399),
-    Complex64::new(0.0857973123444399, 0.996312612182778),
-    Complex64::new(-0.0857973123444399, -0.996312612182778),
-    Complex64::new(0.765167265622459, 0.643831542889791),
-    Complex64::new(-0.765167265622459, -0.643831542889791),
-    Complex64::new(0.643831542889791, -0.765167265622459),
-    Complex64::new(-0.643831542889791, 0.765167265622459),
-    Complex64::new(0.953306040354194, 0.302005949319228),
-    Complex64::new(-0.953306040354194, -0.302005949319228),
-    Complex64::new(0.302005949319228, -0.953306040354194),
-    Complex64::new(-0.302005949319228, 0.953306040354194),
-    Complex64::new(0.887639620402854, -0.460538710958240),
-    Complex64::new(-0.887639620402854, 0.460538710958240),
-    Complex64::new(0.460538710958240, 0.887639620402854),
-    Complex64::new(-0.460538710958240, -0.887639620402854),
-    Complex64::new(0.993906970002356, 0.110222207293883),
-    Complex64::new(-0.993906970002356, -0.110222207293883),
-    Complex64::new(0.110222207293883, -0.993906970002356),
-    Complex64::new(-0.110222207293883, 0.993906970002356),
-    Complex64::new(0.780737228572094, -0.624859488142386),
-    Complex64::new(-0.780737228572094, 0.624859488142386),
-    Complex64::new(0.624859488142386, 0.780737228572094),
-    Complex64::new(-0.624859488142386, -0.780737228572094),
-    Complex64::new(0.960430519415566, -0.278519689385053),
-    Complex64::new(-0.960430519415566, 0.278519689385053),
-    Complex64::new(0.278519689385053, 0.960430519415566),
-    Complex64::new(-0.278519689385053, -0.960430519415566),
-    Complex64::new(0.876070094195407, 0.482183772079123),
-    Complex64::new(-0.876070094195407, -0.482183772079123),
-    Complex64::new(0.482183772079123, -0.876070094195407),
-    Complex64::new(-0.482183772079123, 0.876070094195407),
-    Complex64::new(0.999322384588350, -0.0368072229413588),
-    Complex64::new(-0.999322384588350, 0.0368072229413588),
-    Complex64::new(0.0368072229413588, 0.999322384588350),
-    Complex64::new(-0.0368072229413588, -0.999322384588350),
-    Complex64::new(0.732654271672413, 0.680600997795453),
-    Complex64::new(-0.732654271672413, -0.680600997795453),
-    Complex64::new(0.680600997795453, -0.732654271672413),
-    Complex64::new(-0.680600997795453, 0.732654271672413),
-    Complex64::new(0.937339011912575, 0.348418680249435),
-    Complex64::new(-0.937339011912575, -0.348418680249435),
-    Complex64::new(0.348418680249435, -0.937339011912575),
-    Complex64::new(-0.348418680249435, 0.937339011912575),
-    Complex64::new(0.909167983090522, -0.416429560097637),
-    Complex64::new(-0.909167983090522, 0.416429560097637),
-    Complex64::new(0.416429560097637, 0.909167983090522),
-    Complex64::new(-0.416429560097637, -0.909167983090522),
-    Complex64::new(0.987301418157858, 0.158858143333861),
-    Complex64::new(-0.987301418157858, -0.158858143333861),
-    Complex64::new(0.158858143333861, -0.987301418157858),
-    Complex64::new(-0.158858143333861, 0.987301418157858),
-    Complex64::new(0.810457198252595, -0.585797857456439),
-    Complex64::new(-0.810457198252595, 0.585797857456439),
-    Complex64::new(0.585797857456439, 0.810457198252595),
-    Complex64::new(-0.585797857456439, -0.810457198252595),
-    Complex64::new(0.972939952205560, -0.231058108280671),
-    Complex64::new(-0.972939952205560, 0.231058108280671),
-    Complex64::new(0.231058108280671, 0.972939952205560),
-    Complex64::new(-0.231058108280671, -0.972939952205560),
-    Complex64::new(0.851355193105265, 0.524589682678469),
-    Complex64::new(-0.851355193105265, -0.524589682678469),
-    Complex64::new(0.524589682678469, -0.851355193105265),
-    Complex64::new(-0.524589682678469, 0.851355193105265),
-    Complex64::new(0.998118112900149, 0.0613207363022086),
-    Complex64::new(-0.998118112900149, -0.0613207363022086),
-    Complex64::new(0.0613207363022086, -0.998118112900149),
-    Complex64::new(-0.0613207363022086, 0.998118112900149),
-    Complex64::new(0.749136394523459, -0.662415777590172),
-    Complex64::new(-0.749136394523459, 0.662415777590172),
-    Complex64::new(0.662415777590172, 0.749136394523459),
-    Complex64::new(-0.662415777590172, -0.749136394523459),
-    Complex64::new(0.945607325380521, -0.325310292162263),
-    Complex64::new(-0.945607325380521, 0.325310292162263),
-    Complex64::new(0.325310292162263, 0.945607325380521),
-    Complex64::new(-0.325310292162263, -0.945607325380521),
-    Complex64::new(0.898674465693954, 0.438616238538528),
-    Complex64::new(-0.898674465693954, -0.438616238538528),
-    Complex64::new(0.438616238538528, -0.898674465693954),
-    Complex64::new(-0.438616238538528, 0.898674465693954),
-    Complex64::new(0.990902635427780, -0.134580708507126),
-    Complex64::new(-0.990902635427780, 0.134580708507126),
-    Complex64::new(0.134580708507126, 0.990902635427780),
-    Complex64::new(-0.134580708507126, -0.990902635427780),
-    Complex64::new(0.795836904608884, 0.605511041404325),
-    Complex64::new(-0.795836904608884, -0.605511041404325),
-    Complex64::new(0.605511041404326, -0.795836904608883),
-    Complex64::new(-0.605511041404326, 0.795836904608883),
-    Complex64::new(0.966976471044852, 0.254865659604515),
-    Complex64::new(-0.966976471044852, -0.254865659604515),
-    Complex64::new(0.254865659604515, -0.966976471044852),
-    Complex64::new(-0.254865659604515, 0.966976471044852),
-    Complex64::new(0.863972856121587, -0.503538383725718),
-    Complex64::new(-0.863972856121587, 0.503538383725718),
-    Complex64::new(0.503538383725718, 0.863972856121587),
-    Complex64::new(-0.503538383725718, -0.863972856121587),
-    Complex64::new(0.999924701839145, -0.0122715382857199),
-    Complex64::new(-0.999924701839145, 0.0122715382857199),
-    Complex64::new(0.0122715382857199, 0.999924701839145),
-    Complex64::new(-0.0122715382857199, -0.999924701839145),
-    Complex64::new(0.715730825283819, 0.698376249408973),
-    Complex64::new(-0.715730825283819, -0.698376249408973),
-    Complex64::new(0.698376249408973, -0.715730825283819),
-    Complex64::new(-0.698376249408973, 0.715730825283819),
-    Complex64::new(0.928506080473216, 0.371317193951837),
-    Complex64::new(-0.928506080473216, -0.371317193951837),
-    Complex64::new(0.371317193951837, -0.928506080473216),
-    Complex64::new(-0.371317193951837, 0.928506080473216),
-    Complex64::new(0.919113851690058, -0.393992040061048),
-    Complex64::new(-0.919113851690058, 0.393992040061048),
-    Complex64::new(0.393992040061048, 0.919113851690058),
-    Complex64::new(-0.393992040061048, -0.919113851690058),
-    Complex64::new(0.983105487431216, 0.183039887955141),
-    Complex64::new(-0.983105487431216, -0.183039887955141),
-    Complex64::new(0.183039887955141, -0.983105487431216),
-    Complex64::new(-0.183039887955141, 0.983105487431216),
-    Complex64::new(0.824589302785025, -0.565731810783613),
-    Complex64::new(-0.824589302785025, 0.565731810783613),
-    Complex64::new(0.565731810783613, 0.824589302785025),
-    Complex64::new(-0.565731810783613, -0.824589302785025),
-    Complex64::new(0.978317370719628, -0.207111376192219),
-    Complex64::new(-0.978317370719628, 0.207111376192219),
-    Complex64::new(0.207111376192219, 0.978317370719628),
-    Complex64::new(-0.207111376192219, -0.978317370719628),
-    Complex64::new(0.838224705554838, 0.545324988422046),
-    Complex64::new(-0.838224705554838, -0.545324988422046),
-    Complex64::new(0.545324988422046, -0.838224705554838),
-    Complex64::new(-0.545324988422046, 0.838224705554838),
-    Complex64::new(0.996312612182778, 0.0857973123444399),
-    Complex64::new(-0.996312612182778, -0.0857973123444399),
-    Complex64::new(0.0857973123444399, -0.996312612182778),
-    Complex64::new(-0.0857973123444399, 0.996312612182778),
-    Complex64::new(0.765167265622459, -0.643831542889791),
-    Complex64::new(-0.765167265622459, 0.643831542889791),
-    Complex64::new(0.643831542889791, 0.765167265622459),
-    Complex64::new(-0.643831542889791, -0.765167265622459),
-    Complex64::new(0.953306040354194, -0.302005949319228),
-    Complex64::new(-0.953306040354194, 0.302005949319228),
-    Complex64::new(0.302005949319228, 0.953306040354194),
-    Complex64::new(-0.302005949319228, -0.953306040354194),
-    Complex64::new(0.887639620402854, 0.460538710958240),
-    Complex64::new(-0.887639620402854, -0.460538710958240),
-    Complex64::new(0.460538710958240, -0.887639620402854),
-    Complex64::new(-0.460538710958240, 0.887639620402854),
-    Complex64::new(0.993906970002356, -0.110222207293883),
-    Complex64::new(-0.993906970002356, 0.110222207293883),
-    Complex64::new(0.110222207293883, 0.993906970002356),
-    Complex64::new(-0.110222207293883, -0.993906970002356),
-    Complex64::new(0.780737228572094, 0.624859488142386),
-    Complex64::new(-0.780737228572094, -0.624859488142386),
-    Complex64::new(0.624859488142386, -0.780737228572094),
-    Complex64::new(-0.624859488142386, 0.780737228572094),
-    Complex64::new(0.960430519415566, 0.278519689385053),
-    Complex64::new(-0.960430519415566, -0.278519689385053),
-    Complex64::new(0.278519689385053, -0.960430519415566),
-    Complex64::new(-0.278519689385053, 0.960430519415566),
-    Complex64::new(0.876070094195407, -0.482183772079123),
-    Complex64::new(-0.876070094195407, 0.482183772079123),
-    Complex64::new(0.482183772079123, 0.876070094195407),
-    Complex64::new(-0.482183772079123, -0.876070094195407),
-    Complex64::new(0.999322384588350, 0.0368072229413588),
-    Complex64::new(-0.999322384588350, -0.0368072229413588),
-    Complex64::new(0.0368072229413588, -0.999322384588350),
-    Complex64::new(-0.0368072229413588, 0.999322384588350),
-    Complex64::new(0.732654271672413, -0.680600997795453),
-    Complex64::new(-0.732654271672413, 0.680600997795453),
-    Complex64::new(0.680600997795453, 0.732654271672413),
-    Complex64::new(-0.680600997795453, -0.732654271672413),
-    Complex64::new(0.937339011912575, -0.348418680249435),
-    Complex64::new(-0.937339011912575, 0.348418680249435),
-    Complex64::new(0.348418680249435, 0.937339011912575),
-    Complex64::new(-0.348418680249435, -0.937339011912575),
-    Complex64::new(0.909167983090522, 0.416429560097637),
-    Complex64::new(-0.909167983090522, -0.416429560097637),
-    Complex64::new(0.416429560097637, -0.909167983090522),
-    Complex64::new(-0.416429560097637, 0.909167983090522),
-    Complex64::new(0.987301418157858, -0.158858143333861),
-    Complex64::new(-0.987301418157858, 0.158858143333861),
-    Complex64::new(0.158858143333861, 0.987301418157858),
-    Complex64::new(-0.158858143333861, -0.987301418157858),
-    Complex64::new(0.810457198252595, 0.585797857456439),
-    Complex64::new(-0.810457198252595, -0.585797857456439),
-    Complex64::new(0.585797857456439, -0.810457198252595),
-    Complex64::new(-0.585797857456439, 0.810457198252595),
-    Complex64::new(0.972939952205560, 0.231058108280671),
-    Complex64::new(-0.972939952205560, -0.231058108280671),
-    Complex64::new(0.231058108280671, -0.972939952205560),
-    Complex64::new(-0.231058108280671, 0.972939952205560),
-    Complex64::new(0.851355193105265, -0.524589682678469),
-    Complex64::new(-0.851355193105265, 0.524589682678469),
-    Complex64::new(0.524589682678469, 0.851355193105265),
-    Complex64::new(-0.524589682678469, -0.851355193105265),
-    Complex64::new(0.998118112900149, -0.0613207363022086),
-    Complex64::new(-0.998118112900149, 0.0613207363022086),
-    Complex64::new(0.0613207363022086, 0.998118112900149),
-    Complex64::new(-0.0613207363022086, -0.998118112900149),
-    Complex64::new(0.749136394523459, 0.662415777590172),
-    Complex64::new(-0.749136394523459, -0.662415777590172),
-    Complex64::new(0.662415777590172, -0.749136394523459),
-    Complex64::new(-0.662415777590172, 0.749136394523459),
-    Complex64::new(0.945607325380521, 0.325310292162263),
-    Complex64::new(-0.945607325380521, -0.325310292162263),
-    Complex64::new(0.325310292162263, -0.945607325380521),
-    Complex64::new(-0.325310292162263, 0.945607325380521),
-    Complex64::new(0.898674465693954, -0.438616238538528),
-    Complex64::new(-0.898674465693954, 0.438616238538528),
-    Complex64::new(0.438616238538528, 0.898674465693954),
-    Complex64::new(-0.438616238538528, -0.898674465693954),
-    Complex64::new(0.990902635427780, 0.134580708507126),
-    Complex64::new(-0.990902635427780, -0.134580708507126),
-    Complex64::new(0.134580708507126, -0.990902635427780),
-    Complex64::new(-0.134580708507126, 0.990902635427780),
-    Complex64::new(0.795836904608884, -0.605511041404325),
-    Complex64::new(-0.795836904608884, 0.605511041404325),
-    Complex64::new(0.605511041404326, 0.795836904608883),
-    Complex64::new(-0.605511041404326, -0.795836904608883),
-    Complex64::new(0.966976471044852, -0.254865659604515),
-    Complex64::new(-0.966976471044852, 0.254865659604515),
-    Complex64::new(0.254865659604515, 0.966976471044852),
-    Complex64::new(-0.254865659604515, -0.966976471044852),
-    Complex64::new(0.863972856121587, 0.503538383725718),
-    Complex64::new(-0.863972856121587, -0.503538383725718),
-    Complex64::new(0.503538383725718, -0.863972856121587),
-    Complex64::new(-0.503538383725718, 0.863972856121587),
-];
-
-// φ₁₀₂₄ = x⁵¹² + 1 polinomunun kökleri - şimdilik devre dışı
-// Daha sonra tam 1024 elemanlı olarak eklenecek
-/*
-static PHI1024_ROOTS: [Complex64; 1024] = [
-    Complex64::new(0.999981175282601, 0.00613588464915448),
-    Complex64::new(-0.999981175282601, -0.00613588464915448),
-    Complex64::new(0.00613588464915448, -0.999981175282601),
-    Complex64::new(-0.00613588464915448, 0.999981175282601),
-    Complex64::new(0.711432195745216, -0.702754744457225),
-    Complex64::new(-0.711432195745216, 0.702754744457225),
-    Complex64::new(0.702754744457225, 0.711432195745216),
-    Complex64::new(-0.702754744457225, -0.711432195745216),
-    Complex64::new(0.926210242138311, -0.377007410216418),
-    Complex64::new(-0.926210242138311, 0.377007410216418),
-    Complex64::new(0.377007410216418, 0.926210242138311),
-    Complex64::new(-0.377007410216418, -0.926210242138311),
-    Complex64::new(0.921514039342042, 0.388345046698826),
-    Complex64::new(-0.921514039342042, -0.388345046698826),
-    Complex64::new(0.388345046698826, -0.921514039342042),
-    Complex64::new(-0.388345046698826, 0.921514039342042),
-    Complex64::new(0.981963869109555, -0.189068664149806),
-    Complex64::new(-0.981963869109555, 0.189068664149806),
-    Complex64::new(0.189068664149806, 0.981963869109555),
-    Complex64::new(-0.189068664149806, -0.981963869109555),
-    Complex64::new(0.828045045257756, 0.560661576197336),
-    Complex64::new(-0.828045045257756, -0.560661576197336),
-    Complex64::new(0.560661576197336, -0.828045045257756),
-    Complex64::new(-0.560661576197336, 0.828045045257756),
-    Complex64::new(0.979569765685441, 0.201104634842092),
-    Complex64::new(-0.979569765685441, -0.201104634842092),
-    Complex64::new(0.201104634842092, -0.979569765685441),
-    Complex64::new(-0.201104634842092, 0.979569765685441),
-    Complex64::new(0.834862874986380, -0.550457972936605),
-    Complex64::new(-0.834862874986380, 0.550457972936605),
-    Complex64::new(0.550457972936605, 0.834862874986380),
-    Complex64::new(-0.550457972936605, -0.834862874986380),
-    Complex64::new(0.995767414467660, -0.0919089564971327),
-    Complex64::new(-0.995767414467660, 0.0919089564971327),
-    Complex64::new(0.0919089564971327, 0.995767414467660),
-    Complex64::new(-0.0919089564971327, -0.995767414467660),
-    Complex64::new(0.769103337645580, 0.639124444863776),
-    Complex64::new(-0.769103337645580, -0.639124444863776),
-    Complex64::new(0.639124444863776, -0.769103337645580),
-    Complex64::new(-0.639124444863776, 0.769103337645580),
-    Complex64::new(0.955141168305771, 0.296150888243624),
-    Complex64::new(-0.955141168305771, -0.296150888243624),
-    Complex64::new(0.296150888243624, -0.955141168305771),
-    Complex64::new(-0.296150888243624, 0.955141168305771),
-    Complex64::new(0.884797098430938, -0.465976495767966),
-    Complex64::new(-0.884797098430938, 0.465976495767966),
-    Complex64::new(0.465976495767966, 0.884797098430938),
-    Complex64::new(-0.465976495767966, -0.884797098430938),
-    Complex64::new(0.994564570734255, 0.104121633872055),
-    Complex64::new(-0.994564570734255, -0.104121633872055),
-    Complex64::new(0.104121633872055, -0.994564570734255),
-    Complex64::new(-0.104121633872055, 0.994564570734255),
-    Complex64::new(0.776888465673232, -0.629638238914927),
-    Complex64::new(-0.776888465673232, 0.629638238914927),
-    Complex64::new(0.629638238914927, 0.776888465673233),
-    Complex64::new(-0.629638238914927, -0.776888465673233),
-    Complex64::new(0.958703474895871, -0.284407537211272),
-    Complex64::new(-0.958703474895871, 0.284407537211272),
-    Complex64::new(0.284407537211272, 0.958703474895871),
-    Complex64::new(-0.284407537211272, -0.958703474895871),
-    Complex64::new(0.879012226428633, 0.476799230063322),
-    Complex64::new(-0.879012226428633, -0.476799230063322),
-    Complex64::new(0.476799230063322, -0.879012226428634),
-    Complex64::new(-0.476799230063322, 0.879012226428634),
-    Complex64::new(0.999077727752645, -0.0429382569349408),
-    Complex64::new(-0.999077727752645, 0.0429382569349408),
-    Complex64::new(0.0429382569349408, 0.999077727752645),
-    Complex64::new(-0.0429382569349408, -0.999077727752645),
-    Complex64::new(0.736816568877370, 0.676092703575316),
-    Complex64::new(-0.736816568877370, -0.676092703575316),
-    Complex64::new(0.676092703575316, -0.736816568877370),
-    Complex64::new(-0.676092703575316, 0.736816568877370),
-    Complex64::new(0.939459223602190, 0.342660717311994),
-    Complex64::new(-0.939459223602190, -0.342660717311994),
-    Complex64::new(0.342660717311994, -0.939459223602190),
-    Complex64::new(-0.342660717311994, 0.939459223602190),
-    Complex64::new(0.906595704514915, -0.422000270799800),
-    Complex64::new(-0.906595704514915, 0.422000270799800),
-    Complex64::new(0.422000270799800, 0.906595704514915),
-    Complex64::new(-0.422000270799800, -0.906595704514915),
-    Complex64::new(0.988257567730749, 0.152797185258443),
-    Complex64::new(-0.988257567730749, -0.152797185258443),
-    Complex64::new(0.152797185258443, -0.988257567730749),
-    Complex64::new(-0.152797185258443, 0.988257567730749),
-    Complex64::new(0.806847553543799, -0.590759701858874),
-    Complex64::new(-0.806847553543799, 0.590759701858874),
-    Complex64::new(0.590759701858874, 0.806847553543799),
-    Complex64::new(-0.590759701858874, -0.806847553543799),
-    Complex64::new(0.971503890986252, -0.237023605994367),
-    Complex64::new(-0.971503890986252, 0.237023605994367),
-    Complex64::new(0.237023605994367, 0.971503890986252),
-    Complex64::new(-0.237023605994367, -0.971503890986252),
-    Complex64::new(0.854557988365401, 0.519355990165590),
-    Complex64::new(-0.854557988365401, -0.519355990165590),
-    Complex64::new(0.519355990165590, -0.854557988365401),
-    Complex64::new(-0.519355990165590, 0.854557988365401),
-    Complex64::new(0.998475580573295, 0.0551952443496899),
-    Complex64::new(-0.998475580573295, -0.0551952443496899),
-    Complex64::new(0.0551952443496899, -0.998475580573295),
-    Complex64::new(-0.0551952443496899, 0.998475580573295),
-    Complex64::new(0.745057785441466, -0.666999922303638),
-    Complex64::new(-0.745057785441466, 0.666999922303638),
-    Complex64::new(0.666999922303637, 0.745057785441466),
-    Complex64::new(-0.666999922303637, -0.745057785441466),
-    Complex64::new(0.943593458161960, -0.331106305759876),
-    Complex64::new(-0.943593458161960, 0.331106305759876),
-    Complex64::new(0.331106305759876, 0.943593458161960),
-    Complex64::new(-0.331106305759876, -0.943593458161960),
-    Complex64::new(0.901348847046022, 0.433093818853152),
-    Complex64::new(-0.901348847046022, -0.433093818853152),
-    Complex64::new(0.433093818853152, -0.901348847046022),
-    Complex64::new(-0.433093818853152, 0.901348847046022),
-    Complex64::new(0.990058210262297, -0.140658239332849),
-    Complex64::new(-0.990058210262297, 0.140658239332849),
-    Complex64::new(0.140658239332849, 0.990058210262297),
-    Complex64::new(-0.140658239332849, -0.990058210262297),
-    Complex64::new(0.799537269107905, 0.600616479383869),
-    Complex64::new(-0.799537269107905, -0.600616479383869),
-    Complex64::new(0.600616479383869, -0.799537269107905),
-    Complex64::new(-0.600616479383869, 0.799537269107905),
-    Complex64::new(0.968522094274417, 0.248927605745720),
-    Complex64::new(-0.968522094274417, -0.248927605745720),
-    Complex64::new(0.248927605745720, -0.968522094274417),
-    Complex64::new(-0.248927605745720, 0.968522094274417),
-    Complex64::new(0.860866938637767, -0.508830142543107),
-    Complex64::new(-0.860866938637767, 0.508830142543107),
-    Complex64::new(0.508830142543107, 0.860866938637767),
-    Complex64::new(-0.508830142543107, -0.860866938637767),
-    Complex64::new(0.999830581795823, -0.0184067299058048),
-    Complex64::new(-0.999830581795823, 0.0184067299058048),
-    Complex64::new(0.0184067299058048, 0.999830581795823),
-    Complex64::new(-0.0184067299058048, -0.999830581795823),
-    Complex64::new(0.720002507961382, 0.693971460889654),
-    Complex64::new(-0.720002507961382, -0.693971460889654),
-    Complex64::new(0.693971460889654, -0.720002507961382),
-    Complex64::new(-0.693971460889654, 0.720002507961382),
-    Complex64::new(0.930766961078984, 0.365612997804774),
-    Complex64::new(-0.930766961078984, -0.365612997804774),
-    Complex64::new(0.365612997804774, -0.930766961078984),
-    Complex64::new(-0.365612997804774, 0.930766961078984),
-    Complex64::new(0.916679059921043, -0.399624199845647),
-    Complex64::new(-0.916679059921043, 0.399624199845647),
-    Complex64::new(0.399624199845647, 0.916679059921043),
-    Complex64::new(-0.399624199845647, -0.916679059921043),
-    Complex64::new(0.984210092386929, 0.177004220412149),
-    Complex64::new(-0.984210092386929, -0.177004220412149),
-    Complex64::new(0.177004220412149, -0.984210092386929),
-    Complex64::new(-0.177004220412149, 0.984210092386929),
-    Complex64::new(0.821102514991105, -0.570780745886967),
-    Complex64::new(-0.821102514991105, 0.570780745886967),
-    Complex64::new(0.570780745886967, 0.821102514991105),
-    Complex64::new(-0.570780745886967, -0.821102514991105),
-    Complex64::new(0.977028142657754, -0.213110319916091),
-    Complex64::new(-0.977028142657754, 0.213110319916091),
-    Complex64::new(0.213110319916091, 0.977028142657754),
-    Complex64::new(-0.213110319916091, -0.977028142657754),
-    Complex64::new(0.841554977436898, 0.540171472729893),
-    Complex64::new(-0.841554977436898, -0.540171472729893),
-    Complex64::new(0.540171472729893, -0.841554977436898),
-    Complex64::new(-0.540171472729893, 0.841554977436898),
-    Complex64::new(0.996820299291166, 0.0796824379714301),
-    Complex64::new(-0.996820299291166, -0.0796824379714301),
-    Complex64::new(0.0796824379714301, -0.996820299291166),
-    Complex64::new(-0.0796824379714301, 0.996820299291166),
-    Complex64::new(0.761202385484262, -0.648514401022113),
-    Complex64::new(-0.761202385484262, 0.648514401022113),
-    Complex64::new(0.648514401022112, 0.761202385484262),
-    Complex64::new(-0.648514401022112, -0.761202385484262),
-    Complex64::new(0.951435020969008, -0.307849640041535),
-    Complex64::new(-0.951435020969008, 0.307849640041535),
-    Complex64::new(0.307849640041535, 0.951435020969008),
-    Complex64::new(-0.307849640041535, -0.951435020969008),
-    Complex64::new(0.890448723244758, 0.455083587126344),
-    Complex64::new(-0.890448723244758, -0.455083587126344),
-    Complex64::new(0.455083587126344, -0.890448723244758),
-    Complex64::new(-0.455083587126344, 0.890448723244758),
-    Complex64::new(0.993211949234795, -0.116318630911905),
-    Complex64::new(-0.993211949234795, 0.116318630911905),
-    Complex64::new(0.116318630911905, 0.993211949234795),
-    Complex64::new(-0.116318630911905, -0.993211949234795),
-    Complex64::new(0.784556597155575, 0.620057211763289),
-    Complex64::new(-0.784556597155575, -0.620057211763289),
-    Complex64::new(0.620057211763289, -0.784556597155575),
-    Complex64::new(-0.620057211763289, 0.784556597155575),
-    Complex64::new(0.962121404269042, 0.272621355449949),
-    Complex64::new(-0.962121404269042, -0.272621355449949),
-    Complex64::new(0.272621355449949, -0.962121404269042),
-    Complex64::new(-0.272621355449949, 0.962121404269042),
-    Complex64::new(0.873094978418290, -0.487550160148436),
-    Complex64::new(-0.873094978418290, 0.487550160148436),
-    Complex64::new(0.487550160148436, 0.873094978418290),
-    Complex64::new(-0.487550160148436, -0.873094978418290),
-    Complex64::new(0.999529417501093, 0.0306748031766366),
-    Complex64::new(-0.999529417501093, -0.0306748031766366),
-    Complex64::new(0.0306748031766366, -0.999529417501093),
-    Complex64::new(-0.0306748031766366, 0.999529417501093),
-    Complex64::new(0.728464390448225, -0.685083667772700),
-    Complex64::new(-0.728464390448225, 0.685083667772700),
-    Complex64::new(0.685083667772700, 0.728464390448225),
-    Complex64::new(-0.685083667772700, -0.728464390448225),
-    Complex64::new(0.935183509938948, -0.354163525420490),
-    Complex64::new(-0.935183509938948, 0.354163525420490),
-    Complex64::new(0.354163525420490, 0.935183509938948),
-    Complex64::new(-0.354163525420490, -0.935183509938948),
-    Complex64::new(0.911706032005430, 0.410843171057904),
-    Complex64::new(-0.911706032005430, -0.410843171057904),
-    Complex64::new(0.410843171057904, -0.911706032005430),
-    Complex64::new(-0.410843171057904, 0.911706032005430),
-    Complex64::new(0.986308097244599, -0.164913120489970),
-    Complex64::new(-0.986308097244599, 0.164913120489970),
-    Complex64::new(0.164913120489970, 0.986308097244599),
-    Complex64::new(-0.164913120489970, -0.986308097244599),
-    Complex64::new(0.814036329705948, 0.580813958095765),
-    Complex64::new(-0.814036329705948, -0.580813958095765),
-    Complex64::new(0.580813958095765, -0.814036329705948),
-    Complex64::new(-0.580813958095765, 0.814036329705948),
-    Complex64::new(0.974339382785576, 0.225083911359793),
-    Complex64::new(-0.974339382785576, -0.225083911359793),
-    Complex64::new(0.225083911359793, -0.974339382785576),
-    Complex64::new(-0.225083911359793, 0.974339382785576),
-    Complex64::new(0.848120344803297, -0.529803624686295),
-    Complex64::new(-0.848120344803297, 0.529803624686295),
-    Complex64::new(0.529803624686295, 0.848120344803297),
-    Complex64::new(-0.529803624686295, -0.848120344803297),
-    Complex64::new(0.997723066644192, -0.0674439195636641),
-    Complex64::new(-0.997723066644192, 0.0674439195636641),
-    Complex64::new(0.0674439195636641, 0.997723066644192),
-    Complex64::new(-0.0674439195636641, -0.997723066644192),
-    Complex64::new(0.753186799043613, 0.657806693297079),
-    Complex64::new(-0.753186799043613, -0.657806693297079),
-    Complex64::new(0.657806693297079, -0.753186799043613),
-    Complex64::new(-0.657806693297079, 0.753186799043613),
-    Complex64::new(0.947585591017741, 0.319502030816016),
-    Complex64::new(-0.947585591017741, -0.319502030816016),
-    Complex64::new(0.319502030816016, -0.947585591017741),
-    Complex64::new(-0.319502030816016, 0.947585591017741),
-    Complex64::new(0.895966249756185, -0.444122144570429),
-    Complex64::new(-0.895966249756185, 0.444122144570429),
-    Complex64::new(0.444122144570429, 0.895966249756185),
-    Complex64::new(-0.444122144570429, -0.895966249756185),
-    Complex64::new(0.991709753669100, 0.128498110793793),
-    Complex64::new(-0.991709753669100, -0.128498110793793),
-    Complex64::new(0.128498110793793, -0.991709753669100),
-    Complex64::new(-0.128498110793793, 0.991709753669100),
-    Complex64::new(0.792106577300212, -0.610382806276309),
-    Complex64::new(-0.792106577300212, 0.610382806276309),
-    Complex64::new(0.610382806276309, 0.792106577300212),
-    Complex64::new(-0.610382806276309, -0.792106577300212),
-    Complex64::new(0.965394441697689, -0.260794117915276),
-    Complex64::new(-0.965394441697689, 0.260794117915276),
-    Complex64::new(0.260794117915276, 0.965394441697689),
-    Complex64::new(-0.260794117915276, -0.965394441697689),
-    Complex64::new(0.867046245515693, 0.498227666972782),
-    Complex64::new(-0.867046245515693, -0.498227666972782),
-    Complex64::new(0.498227666972782, -0.867046245515693),
-    Complex64::new(-0.498227666972782, 0.867046245515693),
-    Complex64::new(0.999981175282601, -0.00613588464915448),
-    Complex64::new(-0.999981175282601, 0.00613588464915448),
-    Complex64::new(0.00613588464915448, 0.999981175282601),
-    Complex64::new(-0.00613588464915448, -0.999981175282601),
-    Complex64::new(0.711432195745216, 0.702754744457225),
-    Complex64::new(-0.711432195745216, -0.702754744457225),
-    Complex64::new(0.702754744457225, -0.711432195745216),
-    Complex64::new(-0.702754744457225, 0.711432195745216),
-    Complex64::new(0.926210242138311, 0.377007410216418),
-    Complex64::new(-0.926210242138311, -0.377007410216418),
-    Complex64::new(0.377007410216418, -0.926210242138311),
-    Complex64::new(-0.377007410216418, 0.926210242138311),
-    Complex64::new(0.921514039342042, -0.388345046698826),
-    Complex64::new(-0.921514039342042, 0.388345046698826),
-    Complex64::new(0.388345046698826, 0.921514039342042),
-    Complex64::new(-0.388345046698826, -0.921514039342042),
-    Complex64::new(0.981963869109555, 0.189068664149806),
-    Complex64::new(-0.981963869109555, -0.189068664149806),
-    Complex64::new(0.189068664149806, -0.981963869109555),
-    Complex64::new(-0.189068664149806, 0.981963869109555),
-    Complex64::new(0.828045045257756, -0.560661576197336),
-    Complex64::new(-0.828045045257756, 0.560661576197336),
-    Complex64::new(0.560661576197336, 0.828045045257756),
-    Complex64::new(-0.560661576197336, -0.828045045257756),
-    Complex64::new(0.979569765685441, -0.201104634842092),
-    Complex64::new(-0.979569765685441, 0.201104634842092),
-    Complex64::new(0.201104634842092, 0.979569765685441),
-    Complex64::new(-0.201104634842092, -0.979569765685441),
-    Complex64::new(0.834862874986380, 0.550457972936605),
-    Complex64::new(-0.834862874986380, -0.550457972936605),
-    Complex64::new(0.550457972936605, -0.834862874986380),
-    Complex64::new(-0.550457972936605, 0.834862874986380),
-    Complex64::new(0.995767414467660, 0.0919089564971327),
-    Complex64::new(-0.995767414467660, -0.0919089564971327),
-    Complex64::new(0.0919089564971327, -0.995767414467660),
-    Complex64::new(-0.0919089564971327, 0.995767414467660),
-    Complex64::new(0.769103337645580, -0.639124444863776),
-    Complex64::new(-0.769103337645580, 0.639124444863776),
-    Complex64::new(0.639124444863776, 0.769103337645580),
-    Complex64::new(-0.639124444863776, -0.769103337645580),
-    Complex64::new(0.955141168305771, -0.296150888243624),
-    Complex64::new(-0.955141168305771, 0.296150888243624),
-    Complex64::new(0.296150888243624, 0.955141168305771),
-    Complex64::new(-0.296150888243624, -0.955141168305771),
-    Complex64::new(0.884797098430938, 0.465976495767966),
-    Complex64::new(-0.884797098430938, -0.465976495767966),
-    Complex64::new(0.465976495767966, -0.884797098430938),
-    Complex64::new(-0.465976495767966, 0.884797098430938),
-    Complex64::new(0.994564570734255, -0.104121633872055),
-    Complex64::new(-0.994564570734255, 0.104121633872055),
-    Complex64::new(0.104121633872055, 0.994564570734255),
-    Complex64::new(-0.104121633872055, -0.994564570734255),
-    Complex64::new(0.776888465673232, 0.629638238914927),
-    Complex64::new(-0.776888465673232, -0.629638238914927),
-    Complex64::new(0.629638238914927, -0.776888465673233),
-    Complex64::new(-0.629638238914927, 0.776888465673233),
-    Complex64::new(0.958703474895871, 0.284407537211272),
-    Complex64::new(-0.958703474895871, -0.284407537211272),
-    Complex64::new(0.284407537211272, -0.958703474895871),
-    Complex64::new(-0.284407537211272, 0.958703474895871),
-    Complex64::new(0.879012226428633, -0.476799230063322),
-    Complex64::new(-0.879012226428633, 0.476799230063322),
-    Complex64::new(0.476799230063322, 0.879012226428634),
-    Complex64::new(-0.476799230063322, -0.879012226428634),
-    Complex64::new(0.999077727752645, 0.0429382569349408),
-    Complex64::new(-0.999077727752645, -0.0429382569349408),
-    Complex64::new(0.0429382569349408, -0.999077727752645),
-    Complex64::new(-0.0429382569349408, 0.999077727752645),
-    Complex64::new(0.736816568877370, -0.676092703575316),
-    Complex64::new(-0.736816568877370, 0.676092703575316),
-    Complex64::new(0.676092703575316, 0.736816568877370),
-    Complex64::new(-0.676092703575316, -0.736816568877370),
-    Complex64::new(0.939459223602190, -0.342660717311994),
-    Complex64::new(-0.939459223602190, 0.342660717311994),
-    Complex64::new(0.342660717311994, 0.939459223602190),
-    Complex64::new(-0.342660717311994, -0.939459223602190),
-    Complex64::new(0.906595704514915, 0.422000270799800),
-    Complex64::new(-0.906595704514915, -0.422000270799800),
-    Complex64::new(0.422000270799800, -0.906595704514915),
-    Complex64::new(-0.422000270799800, 0.906595704514915),
-    Complex64::new(0.988257567730749, -0.152797185258443),
-    Complex64::new(-0.988257567730749, 0.152797185258443),
-    Complex64::new(0.152797185258443, 0.988257567730749),
-    Complex64::new(-0.152797185258443, -0.988257567730749),
-    Complex64::new(0.806847553543799, 0.590759701858874),
-    Complex64::new(-0.806847553543799, -0.590759701858874),
-    Complex64::new(0.590759701858874, -0.806847553543799),
-    Complex64::new(-0.590759701858874, 0.806847553543799),
-    Complex64::new(0.971503890986252, 0.237023605994367),
-    Complex64::new(-0.971503890986252, -0.237023605994367),
-    Complex64::new(0.237023605994367, -0.971503890986252),
-    Complex64::new(-0.237023605994367, 0.971503890986252),
-    Complex64::new(0.854557988365401, -0.519355990165590),
-    Complex64::new(-0.854557988365401, 0.519355990165590),
-    Complex64::new(0.519355990165590, 0.854557988365401),
-    Complex64::new(-0.519355990165590, -0.854557988365401),
-    Complex64::new(0.998475580573295, -0.0551952443496899),
-    Complex64::new(-0.998475580573295, 0.0551952443496899),
-    Complex64::new(0.0551952443496899, 0.998475580573295),
-    Complex64::new(-0.0551952443496899, -0.998475580573295),
-    Complex64::new(0.745057785441466, 0.666999922303638),
-    Complex64::new(-0.745057785441466, -0.666999922303638),
-    Complex64::new(0.666999922303637, -0.745057785441466),
-    Complex64::new(-0.666999922303637, 0.745057785441466),
-    Complex64::new(0.943593458161960, 0.331106305759876),
-    Complex64::new(-0.943593458161960, -0.331106305759876),
-    Complex64::new(0.331106305759876, -0.943593458161960),
-    Complex64::new(-0.331106305759876, 0.943593458161960),
-    Complex64::new(0.901348847046022, -0.433093818853152),
-    Complex64::new(-0.901348847046022, 0.433093818853152),
-    Complex64::new(0.433093818853152, 0.901348847046022),
-    Complex64::new(-0.433093818853152, -0.901348847046022),
-    Complex64::new(0.990058210262297, 0.140658239332849),
-    Complex64::new(-0.990058210262297, -0.140658239332849),
-    Complex64::new(0.140658239332849, -0.990058210262297),
-    Complex64::new(-0.140658239332849, 0.990058210262297),
-    Complex64::new(0.799537269107905, -0.600616479383869),
-    Complex64::new(-0.799537269107905, 0.600616479383869),
-    Complex64::new(0.600616479383869, 0.799537269107905),
-    Complex64::new(-0.600616479383869, -0.799537269107905),
-    Complex64::new(0.968522094274417, -0.248927605745720),
-    Complex64::new(-0.968522094274417, 0.248927605745720),
-    Complex64::new(0.248927605745720, 0.968522094274417),
-    Complex64::new(-0.248927605745720, -0.968522094274417),
-    Complex64::new(0.860866938637767, 0.508830142543107),
-    Complex64::new(-0.860866938637767, -0.508830142543107),
-    Complex64::new(0.508830142543107, -0.860866938637767),
-    Complex64::new(-0.508830142543107, 0.860866938637767),
-    Complex64::new(0.999830581795823, 0.0184067299058048),
-    Complex64::new(-0.999830581795823, -0.0184067299058048),
-    Complex64::new(0.0184067299058048, -0.999830581795823),
-    Complex64::new(-0.0184067299058048, 0.999830581795823),
-    Complex64::new(0.720002507961382, -0.693971460889654),
-    Complex64::new(-0.720002507961382, 0.693971460889654),
-    Complex64::new(0.693971460889654, 0.720002507961382),
-    Complex64::new(-0.693971460889654, -0.720002507961382),
-    Complex64::new(0.930766961078984, -0.365612997804774),
-    Complex64::new(-0.930766961078984, 0.365612997804774),
-    Complex64::new(0.365612997804774, 0.930766961078984),
-    Complex64::new(-0.365612997804774, -0.930766961078984),
-    Complex64::new(0.916679059921043, 0.399624199845647),
-    Complex64::new(-0.916679059921043, -0.399624199845647),
-    Complex64::new(0.399624199845647, -0.916679059921043),
-    Complex64::new(-0.399624199845647, 0.916679059921043),
-    Complex64::new(0.984210092386929, -0.177004220412149),
-    Complex64::new(-0.984210092386929, 0.177004220412149),
-    Complex64::new(0.177004220412149, 0.984210092386929),
-    Complex64::new(-0.177004220412149, -0.984210092386929),
-    Complex64::new(0.821102514991105, 0.570780745886967),
-    Complex64::new(-0.821102514991105, -0.570780745886967),
-    Complex64::new(0.570780745886967, -0.821102514991105),
-    Complex64::new(-0.570780745886967, 0.821102514991105),
-    Complex64::new(0.977028142657754, 0.213110319916091),
-    Complex64::new(-0.977028142657754, -0.213110319916091),
-    Complex64::new(0.213110319916091, -0.977028142657754),
-    Complex64::new(-0.213110319916091, 0.977028142657754),
-    Complex64::new(0.841554977436898, -0.540171472729893),
-    Complex64::new(-0.841554977436898, 0.540171472729893),
-    Complex64::new(0.540171472729893, 0.841554977436898),
-    Complex64::new(-0.540171472729893, -0.841554977436898),
-    Complex64::new(0.996820299291166, -0.0796824379714301),
-    Complex64::new(-0.996820299291166, 0.0796824379714301),
-    Complex64::new(0.0796824379714301, 0.996820299291166),
-    Complex64::new(-0.0796824379714301, -0.996820299291166),
-    Complex64::new(0.761202385484262, 0.648514401022113),
-    Complex64::new(-0.761202385484262, -0.648514401022113),
-    Complex64::new(0.648514401022112, -0.761202385484262),
-    Complex64::new(-0.648514401022112, 0.761202385484262),
-    Complex64::new(0.951435020969008, 0.307849640041535),
-    Complex64::new(-0.951435020969008, -0.307849640041535),
-    Complex64::new(0.307849640041535, -0.951435020969008),
-    Complex64::new(-0.307849640041535, 0.951435020969008),
-    Complex64::new(0.890448723244758, -0.455083587126344),
-    Complex64::new(-0.890448723244758, 0.455083587126344),
-    Complex64::new(0.455083587126344, 0.890448723244758),
-    Complex64::new(-0.455083587126344, -0.890448723244758),
-    Complex64::new(0.993211949234795, 0.116318630911905),
-    Complex64::new(-0.993211949234795, -0.116318630911905),
-    Complex64::new(0.116318630911905, -0.993211949234795),
-    Complex64::new(-0.116318630911905, 0.993211949234795),
-    Complex64::new(0.784556597155575, -0.620057211763289),
-    Complex64::new(-0.784556597155575, 0.620057211763289),
-    Complex64::new(0.620057211763289, 0.784556597155575),
-    Complex64::new(-0.620057211763289, -0.784556597155575),
-    Complex64::new(0.962121404269042, -0.272621355449949),
-    Complex64::new(-0.962121404269042, 0.272621355449949),
-    Complex64::new(0.272621355449949, 0.962121404269042),
-    Complex64::new(-0.272621355449949, -0.962121404269042),
-    Complex64::new(0.873094978418290, 0.487550160148436),
-    Complex64::new(-0.873094978418290, -0.487550160148436),
-    Complex64::new(0.487550160148436, -0.873094978418290),
-    Complex64::new(-0.487550160148436, 0.873094978418290),
-    Complex64::new(0.999529417501093, -0.0306748031766366),
-    Complex64::new(-0.999529417501093, 0.0306748031766366),
-    Complex64::new(0.0306748031766366, 0.999529417501093),
-    Complex64::new(-0.0306748031766366, -0.999529417501093),
-    Complex64::new(0.728464390448225, 0.685083667772700),
-    Complex64::new(-0.728464390448225, -0.685083667772700),
-    Complex64::new(0.685083667772700, -0.728464390448225),
-    Complex64::new(-0.685083667772700, 0.728464390448225),
-    Complex64::new(0.935183509938948, 0.354163525420490),
-    Complex64::new(-0.935183509938948, -0.354163525420490),
-    Complex64::new(0.354163525420490, -0.935183509938948),
-    Complex64::new(-0.354163525420490, 0.935183509938948),
-    Complex64::new(0.911706032005430, -0.410843171057904),
-    Complex64::new(-0.911706032005430, 0.410843171057904),
-    Complex64::new(0.410843171057904, 0.911706032005430),
-    Complex64::new(-0.410843171057904, -0.911706032005430),
-    Complex64::new(0.986308097244599, 0.164913120489970),
-    Complex64::new(-0.986308097244599, -0.164913120489970),
-    Complex64::new(0.164913120489970, -0.986308097244599),
-    Complex64::new(-0.164913120489970, 0.986308097244599),
-    Complex64::new(0.814036329705948, -0.580813958095765),
-    Complex64::new(-0.814036329705948, 0.580813958095765),
-    Complex64::new(0.580813958095765, 0.814036329705948),
-    Complex64::new(-0.580813958095765, -0.814036329705948),
-    Complex64::new(0.974339382785576, -0.225083911359793),
-    Complex64::new(-0.974339382785576, 0.225083911359793),
-    Complex64::new(0.225083911359793, 0.974339382785576),
-    Complex64::new(-0.225083911359793, -0.974339382785576),
-    Complex64::new(0.848120344803297, 0.529803624686295),
-    Complex64::new(-0.848120344803297, -0.529803624686295),
-    Complex64::new(0.529803624686295, -0.848120344803297),
-    Complex64::new(-0.529803624686295, 0.848120344803297),
-    Complex64::new(0.997723066644192, 0.0674439195636641),
-    Complex64::new(-0.997723066644192, -0.0674439195636641),
-    Complex64::new(0.0674439195636641, -0.997723066644192),
-    Complex64::new(-0.0674439195636641, 0.997723066644192),
-    Complex64::new(0.753186799043613, -0.657806693297079),
-    Complex64::new(-0.753186799043613, 0.657806693297079),
-    Complex64::new(0.657806693297079, 0.753186799043613),
-    Complex64::new(-0.657806693297079, -0.753186799043613),
-    Complex64::new(0.947585591017741, -0.319502030816016),
-    Complex64::new(-0.947585591017741, 0.319502030816016),
-    Complex64::new(0.319502030816016, 0.947585591017741),
-    Complex64::new(-0.319502030816016, -0.947585591017741),
-    Complex64::new(0.895966249756185, 0.444122144570429),
-    Complex64::new(-0.895966249756185, -0.444122144570429),
-    Complex64::new(0.444122144570429, -0.895966249756185),
-    Complex64::new(-0.444122144570429, 0.895966249756185),
-    Complex64::new(0.991709753669100, -0.128498110793793),
-    Complex64::new(-0.991709753669100, 0.128498110793793),
-    Complex64::new(0.128498110793793, 0.991709753669100),
-    Complex64::new(-0.128498110793793, -0.991709753669100),
-    Complex64::new(0.792106577300212, 0.610382806276309),
-    Complex64::new(-0.792106577300212, -0.610382806276309),
-    Complex64::new(0.610382806276309, -0.792106577300212),
-    Complex64::new(-0.610382806276309, 0.792106577300212),
-    Complex64::new(0.965394441697689, 0.260794117915276),
-    Complex64::new(-0.965394441697689, -0.260794117915276),
-    Complex64::new(0.260794117915276, -0.965394441697689),
-    Complex64::new(-0.260794117915276, 0.965394441697689),
-    Complex64::new(0.867046245515693, -0.498227666972782),
-    Complex64::new(-0.867046245515693, 0.498227666972782),
-    Complex64::new(0.498227666972782, 0.867046245515693),
-    Complex64::new(-0.498227666972782, -0.867046245515693),
-];
-*/
-
-// φ₂₀₄₈ = x¹⁰²⁴ + 1 polinomunun kökleri - şimdilik devre dışı
-// Daha sonra tam 2048 elemanlı olarak eklenecek
-/*
-static PHI2048_ROOTS: [Complex64; 2048] = [
-    Complex64::new(0.999995293809576, 0.00306795676296598),
-    Complex64::new(-0.999995293809576, -0.00306795676296598),
-    Complex64::new(0.00306795676296598, -0.999995293809576),
-    Complex64::new(-0.00306795676296598, 0.999995293809576),
-    Complex64::new(0.709272826438866, -0.704934080375905),
-    Complex64::new(-0.709272826438866, 0.704934080375905),
-    Complex64::new(0.704934080375905, 0.709272826438866),
-    Complex64::new(-0.704934080375905, -0.709272826438866),
-    Complex64::new(0.925049240782678, -0.379847208924051),
-    Complex64::new(-0.925049240782678, 0.379847208924051),
-    Complex64::new(0.379847208924051, 0.925049240782678),
-    Complex64::new(-0.379847208924051, -0.925049240782678),
-    Complex64::new(0.922701128333879, 0.385516053843919),
-    Complex64::new(-0.922701128333879, -0.385516053843919),
-    Complex64::new(0.385516053843919, -0.922701128333879),
-    Complex64::new(-0.385516053843919, 0.922701128333879),
-    Complex64::new(0.981379193313755, -0.192080397049892),
-    Complex64::new(-0.981379193313755, 0.192080397049892),
-    Complex64::new(0.192080397049892, 0.981379193313755),
-    Complex64::new(-0.192080397049892, -0.981379193313755),
-    Complex64::new(0.829761233794523, 0.558118531220556),
-    Complex64::new(-0.829761233794523, -0.558118531220556),
-    Complex64::new(0.558118531220556, -0.829761233794523),
-    Complex64::new(-0.558118531220556, 0.829761233794523),
-    Complex64::new(0.980182135968117, 0.198098410717954),
-    Complex64::new(-0.980182135968117, -0.198098410717954),
-    Complex64::new(0.198098410717954, -0.980182135968117),
-    Complex64::new(-0.198098410717954, 0.980182135968117),
-    Complex64::new(0.833170164701913, -0.553016705580028),
-    Complex64::new(-0.833170164701913, 0.553016705580028),
-    Complex64::new(0.553016705580028, 0.833170164701913),
-    Complex64::new(-0.553016705580028, -0.833170164701913),
-    Complex64::new(0.995480755491927, -0.0949634953296390),
-    Complex64::new(-0.995480755491927, 0.0949634953296390),
-    Complex64::new(0.0949634953296390, 0.995480755491927),
-    Complex64::new(-0.0949634953296390, -0.995480755491927),
-    Complex64::new(0.771060524261814, 0.636761861236284),
-    Complex64::new(-0.771060524261814, -0.636761861236284),
-    Complex64::new(0.636761861236284, -0.771060524261814),
-    Complex64::new(-0.636761861236284, 0.771060524261814),
-    Complex64::new(0.956045251349996, 0.293219162694259),
-    Complex64::new(-0.956045251349996, -0.293219162694259),
-    Complex64::new(0.293219162694259, -0.956045251349996),
-    Complex64::new(-0.293219162694259, 0.956045251349996),
-    Complex64::new(0.883363338665732, -0.468688822035828),
-    Complex64::new(-0.883363338665732, 0.468688822035828),
-    Complex64::new(0.468688822035828, 0.883363338665732),
-    Complex64::new(-0.468688822035828, -0.883363338665732),
-    Complex64::new(0.994879330794806, 0.101069862754828),
-    Complex64::new(-0.994879330794806, -0.101069862754828),
-    Complex64::new(0.101069862754828, -0.994879330794806),
-    Complex64::new(-0.101069862754828, 0.994879330794806),
-    Complex64::new(0.774953106594874, -0.632018735939809),
-    Complex64::new(-0.774953106594874, 0.632018735939809),
-    Complex64::new(0.632018735939809, 0.774953106594874),
-    Complex64::new(-0.632018735939809, -0.774953106594874),
-    Complex64::new(0.957826413027533, -0.287347459544730),
-    Complex64::new(-0.957826413027533, 0.287347459544730),
-    Complex64::new(0.287347459544730, 0.957826413027533),
-    Complex64::new(-0.287347459544730, -0.957826413027533),
-    Complex64::new(0.880470889052161, 0.474100214650550),
-    Complex64::new(-0.880470889052161, -0.474100214650550),
-    Complex64::new(0.474100214650550, -0.880470889052161),
-    Complex64::new(-0.474100214650550, 0.880470889052161),
-    Complex64::new(0.998941293186857, -0.0460031821309146),
-    Complex64::new(-0.998941293186857, 0.0460031821309146),
-    Complex64::new(0.0460031821309146, 0.998941293186857),
-    Complex64::new(-0.0460031821309146, -0.998941293186857),
-    Complex64::new(0.738887324460615, 0.673829000378756),
-    Complex64::new(-0.738887324460615, -0.673829000378756),
-    Complex64::new(0.673829000378756, -0.738887324460615),
-    Complex64::new(-0.673829000378756, 0.738887324460615),
-    Complex64::new(0.940506070593268, 0.339776884406827),
-    Complex64::new(-0.940506070593268, -0.339776884406827),
-    Complex64::new(0.339776884406827, -0.940506070593268),
-    Complex64::new(-0.339776884406827, 0.940506070593268),
-    Complex64::new(0.905296759318119, -0.424779681209109),
-    Complex64::new(-0.905296759318119, 0.424779681209109),
-    Complex64::new(0.424779681209109, 0.905296759318119),
-    Complex64::new(-0.424779681209109, -0.905296759318119),
-    Complex64::new(0.988721691960324, 0.149764534677322),
-    Complex64::new(-0.988721691960324, -0.149764534677322),
-    Complex64::new(0.149764534677322, -0.988721691960324),
-    Complex64::new(-0.149764534677322, 0.988721691960324),
-    Complex64::new(0.805031331142964, -0.593232295039800),
-    Complex64::new(-0.805031331142964, 0.593232295039800),
-    Complex64::new(0.593232295039800, 0.805031331142964),
-    Complex64::new(-0.593232295039800, -0.805031331142964),
-    Complex64::new(0.970772140728950, -0.240003022448741),
-    Complex64::new(-0.970772140728950, 0.240003022448741),
-    Complex64::new(0.240003022448741, 0.970772140728950),
-    Complex64::new(-0.240003022448741, -0.970772140728950),
-    Complex64::new(0.856147328375194, 0.516731799017650),
-    Complex64::new(-0.856147328375194, -0.516731799017650),
-    Complex64::new(0.516731799017650, -0.856147328375194),
-    Complex64::new(-0.516731799017650, 0.856147328375194),
-    Complex64::new(0.998640218180265, 0.0521317046802833),
-    Complex64::new(-0.998640218180265, -0.0521317046802833),
-    Complex64::new(0.0521317046802833, -0.998640218180265),
-    Complex64::new(-0.0521317046802833, 0.998640218180265),
-    Complex64::new(0.743007952135122, -0.669282588346636),
-    Complex64::new(-0.743007952135122, 0.669282588346636),
-    Complex64::new(0.669282588346636, 0.743007952135122),
-    Complex64::new(-0.669282588346636, -0.743007952135122),
-    Complex64::new(0.942573197601447, -0.333999651442009),
-    Complex64::new(-0.942573197601447, 0.333999651442009),
-    Complex64::new(0.333999651442009, 0.942573197601447),
-    Complex64::new(-0.333999651442009, -0.942573197601447),
-    Complex64::new(0.902673318237259, 0.430326481340083),
-    Complex64::new(-0.902673318237259, -0.430326481340083),
-    Complex64::new(0.430326481340083, -0.902673318237259),
-    Complex64::new(-0.430326481340083, 0.902673318237259),
-    Complex64::new(0.989622017463201, -0.143695033150294),
-    Complex64::new(-0.989622017463201, 0.143695033150294),
-    Complex64::new(0.143695033150294, 0.989622017463201),
-    Complex64::new(-0.143695033150294, -0.989622017463201),
-    Complex64::new(0.801376171723140, 0.598160706996342),
-    Complex64::new(-0.801376171723140, -0.598160706996342),
-    Complex64::new(0.598160706996342, -0.801376171723140),
-    Complex64::new(-0.598160706996342, 0.801376171723140),
-    Complex64::new(0.969281235356549, 0.245955050335795),
-    Complex64::new(-0.969281235356549, -0.245955050335795),
-    Complex64::new(0.245955050335795, -0.969281235356549),
-    Complex64::new(-0.245955050335795, 0.969281235356549),
-    Complex64::new(0.859301818357008, -0.511468850437970),
-    Complex64::new(-0.859301818357008, 0.511468850437970),
-    Complex64::new(0.511468850437970, 0.859301818357008),
-    Complex64::new(-0.511468850437970, -0.859301818357008),
-    Complex64::new(0.999769405351215, -0.0214740802754695),
-    Complex64::new(-0.999769405351215, 0.0214740802754695),
-    Complex64::new(0.0214740802754695, 0.999769405351215),
-    Complex64::new(-0.0214740802754695, -0.999769405351215),
-    Complex64::new(0.722128193929215, 0.691759258364158),
-    Complex64::new(-0.722128193929215, -0.691759258364158),
-    Complex64::new(0.691759258364158, -0.722128193929215),
-    Complex64::new(-0.691759258364158, 0.722128193929215),
-    Complex64::new(0.931884265581668, 0.362755724367397),
-    Complex64::new(-0.931884265581668, -0.362755724367397),
-    Complex64::new(0.362755724367397, -0.931884265581668),
-    Complex64::new(-0.362755724367397, 0.931884265581668),
-    Complex64::new(0.915448716088268, -0.402434650859418),
-    Complex64::new(-0.915448716088268, 0.402434650859418),
-    Complex64::new(0.402434650859418, 0.915448716088268),
-    Complex64::new(-0.402434650859418, 0.915448716088268),
-    Complex64::new(0.984748501801904, 0.173983873387464),
-    Complex64::new(-0.984748501801904, 0.173983873387464),
-    Complex64::new(0.173983873387464, 0.984748501801904),
-    Complex64::new(-0.173983873387464, 0.984748501801904),
-    Complex64::new(0.819347520076797, -0.573297166698042),
-    Complex64::new(-0.819347520076797, 0.573297166698042),
-    Complex64::new(0.573297166698042, 0.819347520076797),
-    Complex64::new(-0.573297166698042, -0.819347520076797),
-    Complex64::new(0.976369731330021, -0.216106797076220),
-    Complex64::new(-0.976369731330021, 0.216106797076220),
-    Complex64::new(0.216106797076220, 0.976369731330021),
-    Complex64::new(-0.216106797076220, 0.976369731330021),
-    Complex64::new(0.843208239641845, 0.537587076295646),
-    Complex64::new(-0.843208239641845, -0.537587076295646),
-    Complex64::new(0.537587076295646, -0.843208239641845),
-    Complex64::new(-0.537587076295646, 0.843208239641845),
-    Complex64::new(0.997060070339483, 0.0766238613920315),
-    Complex64::new(-0.997060070339483, -0.0766238613920315),
-    Complex64::new(0.0766238613920315, -0.997060070339483),
-    Complex64::new(-0.0766238613920315, 0.997060070339483),
-    Complex64::new(0.759209188978388, -0.650846684996381),
-    Complex64::new(-0.759209188978388, 0.650846684996381),
-    Complex64::new(0.950486073949482, -0.310767152749612),
-    Complex64::new(-0.950486073949482, 0.310767152749612),
-    Complex64::new(0.310767152749612, 0.950486073949482),
-    Complex64::new(-0.310767152749612, -0.950486073949482),
-    Complex64::new(0.452349587233771, -0.891840709392343),
-    Complex64::new(-0.452349587233771, 0.891840709392343),
-    Complex64::new(0.992850414459865, -0.119365214810991),
-    Complex64::new(-0.992850414459865, 0.119365214810991),
-    Complex64::new(0.119365214810991, 0.992850414459865),
-    Complex64::new(-0.119365214810991, -0.992850414459865),
-    Complex64::new(0.786455213599086, 0.617647307937804),
-    Complex64::new(-0.786455213599086, -0.617647307937804),
-    Complex64::new(0.617647307937804, -0.786455213599086),
-    Complex64::new(-0.617647307937804, 0.786455213599086),
-    Complex64::new(0.962953266873684, 0.269668325572915),
-    Complex64::new(-0.962953266873684, -0.269668325572915),
-    Complex64::new(0.269668325572915, -0.962953266873684),
-    Complex64::new(-0.269668325572915, 0.962953266873684),
-    Complex64::new(0.871595086655951, -0.490226483288291),
-    Complex64::new(-0.871595086655951, 0.490226483288291),
-    Complex64::new(0.490226483288291, 0.871595086655951),
-    Complex64::new(-0.490226483288291, -0.871595086655951),
-    Complex64::new(0.999618822495179, 0.0276081457789657),
-    Complex64::new(-0.999618822495179, -0.0276081457789657),
-    Complex64::new(0.0276081457789657, -0.999618822495179),
-    Complex64::new(-0.0276081457789657, 0.999618822495179),
-    Complex64::new(0.726359155084346, -0.687315340891759),
-    Complex64::new(-0.726359155084346, 0.687315340891759),
-    Complex64::new(0.687315340891759, 0.726359155084346),
-    Complex64::new(-0.687315340891759, -0.726359155084346),
-    Complex64::new(0.934092550404259, -0.357030961233430),
-    Complex64::new(-0.934092550404259, 0.357030961233430),
-    Complex64::new(0.357030961233430, 0.934092550404259),
-    Complex64::new(-0.357030961233430, -0.934092550404259),
-    Complex64::new(0.912962190428398, 0.408044162864979),
-    Complex64::new(-0.912962190428398, -0.408044162864979),
-    Complex64::new(0.408044162864979, -0.912962190428398),
-    Complex64::new(-0.408044162864979, 0.912962190428398),
-    Complex64::new(0.985797509167567, -0.167938294974731),
-    Complex64::new(-0.985797509167567, 0.167938294974731),
-    Complex64::new(0.167938294974731, 0.985797509167567),
-    Complex64::new(-0.167938294974731, -0.985797509167567),
-    Complex64::new(0.815814410806734, 0.578313796411656),
-    Complex64::new(-0.815814410806734, -0.578313796411656),
-    Complex64::new(0.578313796411656, -0.815814410806734),
-    Complex64::new(-0.578313796411656, 0.815814410806734),
-    Complex64::new(0.975025345066994, 0.222093620973204),
-    Complex64::new(-0.975025345066994, -0.222093620973204),
-    Complex64::new(0.222093620973204, -0.975025345066994),
-    Complex64::new(-0.222093620973204, 0.975025345066994),
-    Complex64::new(0.846490938774052, -0.532403127877198),
-    Complex64::new(-0.846490938774052, 0.532403127877198),
-    Complex64::new(0.532403127877198, 0.846490938774052),
-    Complex64::new(-0.532403127877198, -0.846490938774052),
-    Complex64::new(0.997511456140303, -0.0705045733896139),
-    Complex64::new(-0.997511456140303, 0.0705045733896139),
-    Complex64::new(0.0705045733896139, 0.997511456140303),
-    Complex64::new(-0.0705045733896139, -0.997511456140303),
-    Complex64::new(0.755201376896537, 0.655492852999615),
-    Complex64::new(-0.755201376896537, -0.655492852999615),
-    Complex64::new(0.655492852999615, -0.755201376896537),
-    Complex64::new(-0.655492852999615, 0.755201376896537),
-    Complex64::new(0.948561349915730, 0.316593375556166),
-    Complex64::new(-0.948561349915730, -0.316593375556166),
-    Complex64::new(0.316593375556166, -0.948561349915730),
-    Complex64::new(-0.316593375556166, 0.948561349915730),
-    Complex64::new(0.894599485631383, -0.446868840162374),
-    Complex64::new(-0.894599485631383, 0.446868840162374),
-    Complex64::new(0.446868840162374, 0.894599485631383),
-    Complex64::new(-0.446868840162374, -0.894599485631383),
-    Complex64::new(0.992099313142192, 0.125454983411546),
-    Complex64::new(-0.992099313142192, -0.125454983411546),
-    Complex64::new(0.125454983411546, -0.992099313142192),
-    Complex64::new(-0.125454983411546, 0.992099313142192),
-    Complex64::new(0.790230221437310, -0.612810082429410),
-    Complex64::new(-0.790230221437310, 0.612810082429410),
-    Complex64::new(0.612810082429410, 0.790230221437310),
-    Complex64::new(-0.612810082429410, -0.790230221437310),
-    Complex64::new(0.964589793289813, -0.263754678974831),
-    Complex64::new(-0.964589793289813, 0.263754678974831),
-    Complex64::new(0.263754678974831, 0.964589793289813),
-    Complex64::new(-0.263754678974831, -0.964589793289813),
-    Complex64::new(0.868570705971341, 0.495565261825772),
-    Complex64::new(-0.868570705971341, -0.495565261825772),
-    Complex64::new(0.495565261825772, -0.868570705971341),
-    Complex64::new(-0.495565261825772, 0.868570705971341),
-    Complex64::new(0.999957644551964, -0.00920375478205982),
-    Complex64::new(-0.999957644551964, 0.00920375478205982),
-    Complex64::new(0.00920375478205982, 0.999957644551964),
-    Complex64::new(-0.00920375478205982, -0.999957644551964),
-    Complex64::new(0.713584868780794, 0.700568793943248),
-    Complex64::new(-0.713584868780794, -0.700568793943248),
-    Complex64::new(0.700568793943248, -0.713584868780794),
-    Complex64::new(-0.700568793943248, 0.713584868780794),
-    Complex64::new(0.374164062971458, -0.927362525650401),
-    Complex64::new(-0.374164062971458, 0.927362525650401),
-    Complex64::new(0.920318276709110, -0.391170384302254),
-    Complex64::new(-0.920318276709110, 0.391170384302254),
-    Complex64::new(0.391170384302254, 0.920318276709111),
-    Complex64::new(-0.391170384302254, -0.920318276709111),
-    Complex64::new(0.982539302287441, 0.186055151663447),
-    Complex64::new(-0.982539302287441, -0.186055151663447),
-    Complex64::new(0.186055151663447, 0.982539302287441),
-    Complex64::new(-0.186055151663447, -0.982539302287441),
-    Complex64::new(0.826321062845664, -0.563199344013834),
-    Complex64::new(-0.826321062845664, 0.563199344013834),
-    Complex64::new(0.563199344013834, 0.826321062845664),
-    Complex64::new(-0.563199344013834, -0.826321062845664),
-    Complex64::new(0.204108966092817, 0.978948175319062),
-    Complex64::new(-0.204108966092817, -0.978948175319062),
-    Complex64::new(0.836547727223512, 0.547894059173100),
-    Complex64::new(-0.836547727223512, -0.547894059173100),
-    Complex64::new(0.547894059173100, -0.836547727223512),
-    Complex64::new(-0.547894059173100, 0.836547727223512),
-    Complex64::new(0.996044700901252, 0.0888535525825246),
-    Complex64::new(-0.996044700901252, -0.0888535525825246),
-    Complex64::new(0.0888535525825246, -0.996044700901252),
-    Complex64::new(-0.0888535525825246, 0.996044700901252),
-    Complex64::new(0.767138911935820, -0.641481012808583),
-    Complex64::new(-0.767138911935820, 0.641481012808583),
-    Complex64::new(0.641481012808583, 0.767138911935820),
-    Complex64::new(-0.641481012808583, -0.767138911935820),
-    Complex64::new(0.954228095109106, -0.299079826308040),
-    Complex64::new(-0.954228095109106, 0.299079826308040),
-    Complex64::new(0.299079826308040, 0.954228095109106),
-    Complex64::new(-0.299079826308040, -0.954228095109106),
-    Complex64::new(0.886222530148881, 0.463259783551860),
-    Complex64::new(-0.886222530148881, -0.463259783551860),
-    Complex64::new(0.463259783551860, -0.886222530148881),
-    Complex64::new(-0.463259783551860, 0.886222530148881),
-    Complex64::new(0.994240449453188, -0.107172424956809),
-    Complex64::new(-0.994240449453188, 0.107172424956809),
-    Complex64::new(0.107172424956809, 0.994240449453188),
-    Complex64::new(-0.107172424956809, -0.994240449453188),
-    Complex64::new(0.778816512381476, 0.627251815495144),
-    Complex64::new(-0.778816512381476, -0.627251815495144),
-    Complex64::new(0.627251815495144, -0.778816512381476),
-    Complex64::new(-0.627251815495144, 0.778816512381476),
-    Complex64::new(0.959571513081985, 0.281464937925758),
-    Complex64::new(-0.959571513081985, -0.281464937925758),
-    Complex64::new(0.281464937925758, -0.959571513081985),
-    Complex64::new(-0.281464937925758, 0.959571513081985),
-    Complex64::new(0.877545290207261, -0.479493757660153),
-    Complex64::new(-0.877545290207261, 0.479493757660153),
-    Complex64::new(0.479493757660153, 0.877545290207261),
-    Complex64::new(-0.479493757660153, -0.877545290207261),
-    Complex64::new(0.999204758618364, 0.0398729275877398),
-    Complex64::new(-0.999204758618364, -0.0398729275877398),
-    Complex64::new(0.0398729275877398, -0.999204758618364),
-    Complex64::new(-0.0398729275877398, 0.999204758618364),
-    Complex64::new(0.734738878095963, -0.678350043129862),
-    Complex64::new(-0.734738878095963, 0.678350043129862),
-    Complex64::new(0.678350043129861, 0.734738878095963),
-    Complex64::new(-0.678350043129861, -0.734738878095963),
-    Complex64::new(0.938403534063108, -0.345541324963989),
-    Complex64::new(-0.938403534063108, 0.345541324963989),
-    Complex64::new(0.345541324963989, 0.938403534063108),
-    Complex64::new(-0.345541324963989, -0.938403534063108),
-    Complex64::new(0.907886116487666, 0.419216888363224),
-    Complex64::new(-0.907886116487666, -0.419216888363224),
-    Complex64::new(0.419216888363224, -0.907886116487666),
-    Complex64::new(-0.419216888363224, 0.907886116487666),
-    Complex64::new(0.155828397654265, 0.987784141644572),
-    Complex64::new(-0.155828397654265, -0.987784141644572),
-    Complex64::new(0.808656181588175, 0.588281548222645),
-    Complex64::new(-0.808656181588175, -0.588281548222645),
-    Complex64::new(0.588281548222645, -0.808656181588175),
-    Complex64::new(-0.588281548222645, 0.808656181588175),
-    Complex64::new(0.972226497078936, 0.234041958583543),
-    Complex64::new(-0.972226497078936, -0.234041958583543),
-    Complex64::new(0.234041958583543, -0.972226497078936),
-    Complex64::new(-0.234041958583543, 0.972226497078936),
-    Complex64::new(0.852960604930364, -0.521975292937154),
-    Complex64::new(-0.852960604930364, 0.521975292937154),
-    Complex64::new(0.521975292937154, 0.852960604930364),
-    Complex64::new(-0.521975292937154, -0.852960604930364),
-    Complex64::new(0.998301544933893, -0.0582582645004358),
-    Complex64::new(-0.998301544933893, 0.0582582645004358),
-    Complex64::new(0.0582582645004358, 0.998301544933893),
-    Complex64::new(-0.0582582645004358, -0.998301544933893),
-    Complex64::new(0.747100605980180, 0.664710978203345),
-    Complex64::new(-0.747100605980180, -0.664710978203345),
-    Complex64::new(0.664710978203345, -0.747100605980180),
-    Complex64::new(-0.664710978203345, 0.747100605980180),
-    Complex64::new(0.944604837261480, 0.328209843579092),
-    Complex64::new(-0.944604837261480, -0.328209843579092),
-    Complex64::new(0.328209843579092, -0.944604837261480),
-    Complex64::new(-0.328209843579092, 0.944604837261480),
-    Complex64::new(0.900015892016160, -0.435857079922255),
-    Complex64::new(-0.900015892016160, 0.435857079922255),
-    Complex64::new(0.435857079922256, 0.900015892016160),
-    Complex64::new(-0.435857079922256, -0.900015892016160),
-    Complex64::new(0.990485084256457, 0.137620121586486),
-    Complex64::new(-0.990485084256457, -0.137620121586486),
-    Complex64::new(0.137620121586486, -0.990485084256457),
-    Complex64::new(-0.137620121586486, 0.990485084256457),
-    Complex64::new(0.797690840943391, -0.603066598540348),
-    Complex64::new(-0.797690840943391, 0.603066598540348),
-    Complex64::new(0.603066598540348, 0.797690840943391),
-    Complex64::new(-0.603066598540348, -0.797690840943391),
-    Complex64::new(0.967753837093475, -0.251897818154217),
-    Complex64::new(-0.967753837093475, 0.251897818154217),
-    Complex64::new(0.251897818154217, 0.967753837093475),
-    Complex64::new(-0.251897818154217, -0.967753837093475),
-    Complex64::new(0.862423956111041, 0.506186645345155),
-    Complex64::new(-0.862423956111041, -0.506186645345155),
-    Complex64::new(0.506186645345155, -0.862423956111041),
-    Complex64::new(-0.506186645345155, 0.862423956111041),
-    Complex64::new(0.999882347454213, 0.0153392062849881),
-    Complex64::new(-0.999882347454213, -0.0153392062849881),
-    Complex64::new(0.0153392062849881, -0.999882347454213),
-    Complex64::new(-0.0153392062849881, 0.999882347454213),
-    Complex64::new(0.717870045055732, -0.696177131491463),
-    Complex64::new(-0.717870045055732, 0.696177131491463),
-    Complex64::new(0.696177131491463, 0.717870045055732),
-    Complex64::new(-0.696177131491463, -0.717870045055732),
-    Complex64::new(0.929640895843181, -0.368466829953372),
-    Complex64::new(-0.929640895843181, 0.368466829953372),
-    Complex64::new(0.368466829953372, 0.929640895843181),
-    Complex64::new(-0.368466829953372, -0.929640895843181),
-    Complex64::new(0.917900775621390, 0.396809987416710),
-    Complex64::new(-0.917900775621390, -0.396809987416710),
-    Complex64::new(0.396809987416710, -0.917900775621390),
-    Complex64::new(-0.396809987416710, 0.917900775621390),
-    Complex64::new(0.983662419211730, -0.180022901405700),
-    Complex64::new(-0.983662419211730, 0.180022901405700),
-    Complex64::new(0.180022901405700, 0.983662419211730),
-    Complex64::new(-0.180022901405700, -0.983662419211730),
-    Complex64::new(0.822849781375826, 0.568258952670132),
-    Complex64::new(-0.822849781375826, -0.568258952670132),
-    Complex64::new(0.568258952670132, -0.822849781375826),
-    Complex64::new(-0.568258952670132, 0.822849781375826),
-    Complex64::new(0.977677357824510, 0.210111836880470),
-    Complex64::new(-0.977677357824510, -0.210111836880470),
-    Complex64::new(0.210111836880470, -0.977677357824510),
-    Complex64::new(-0.210111836880470, 0.977677357824510),
-    Complex64::new(0.839893794196000, -0.542750784864516),
-    Complex64::new(-0.839893794196000, 0.542750784864516),
-    Complex64::new(0.542750784864516, 0.839893794196000),
-    Complex64::new(-0.542750784864516, -0.839893794196000),
-    Complex64::new(0.996571145790555, -0.0827402645493757),
-    Complex64::new(-0.996571145790555, 0.0827402645493757),
-    Complex64::new(0.0827402645493757, 0.996571145790555),
-    Complex64::new(-0.0827402645493757, -0.996571145790555),
-    Complex64::new(0.763188417263381, 0.646176012983316),
-    Complex64::new(-0.763188417263381, -0.646176012983316),
-    Complex64::new(0.646176012983316, -0.763188417263381),
-    Complex64::new(-0.646176012983316, 0.763188417263381),
-    Complex64::new(0.952375012719766, 0.304929229735402),
-    Complex64::new(-0.952375012719766, -0.304929229735402),
-    Complex64::new(0.304929229735402, -0.952375012719766),
-    Complex64::new(-0.304929229735402, 0.952375012719766),
-    Complex64::new(0.889048355854665, -0.457813303598877),
-    Complex64::new(-0.889048355854665, 0.457813303598877),
-    Complex64::new(0.457813303598877, 0.889048355854665),
-    Complex64::new(-0.457813303598877, -0.889048355854665),
-    Complex64::new(0.993564135520595, 0.113270952177564),
-    Complex64::new(-0.993564135520595, -0.113270952177564),
-    Complex64::new(0.113270952177564, -0.993564135520595),
-    Complex64::new(-0.113270952177564, 0.993564135520595),
-    Complex64::new(0.782650596166576, -0.622461279374150),
-    Complex64::new(-0.782650596166576, 0.622461279374150),
-    Complex64::new(0.622461279374150, 0.782650596166576),
-    Complex64::new(-0.622461279374150, -0.782650596166576),
-    Complex64::new(0.961280485811321, -0.275571819310958),
-    Complex64::new(-0.961280485811321, 0.275571819310958),
-    Complex64::new(0.275571819310958, 0.961280485811321),
-    Complex64::new(-0.275571819310958, -0.961280485811321),
-    Complex64::new(0.874586652278176, 0.484869248000791),
-    Complex64::new(-0.874586652278176, -0.484869248000791),
-    Complex64::new(0.484869248000791, -0.874586652278176),
-    Complex64::new(-0.484869248000791, 0.874586652278176),
-    Complex64::new(0.999430604555462, -0.0337411718513776),
-    Complex64::new(-0.999430604555462, 0.0337411718513776),
-    Complex64::new(0.0337411718513776, 0.999430604555462),
-    Complex64::new(-0.0337411718513776, -0.999430604555462),
-    Complex64::new(0.730562769227828, 0.682845546385248),
-    Complex64::new(-0.730562769227828, -0.682845546385248),
-    Complex64::new(0.682845546385248, -0.730562769227828),
-    Complex64::new(-0.682845546385248, 0.730562769227828),
-    Complex64::new(0.936265667170278, 0.351292756085567),
-    Complex64::new(-0.936265667170278, -0.351292756085567),
-    Complex64::new(0.351292756085567, -0.936265667170278),
-    Complex64::new(-0.351292756085567, 0.936265667170278),
-    Complex64::new(0.910441292258067, -0.413638312238435),
-    Complex64::new(-0.910441292258067, 0.413638312238435),
-    Complex64::new(0.413638312238435, 0.910441292258067),
-    Complex64::new(-0.413638312238435, -0.910441292258067),
-    Complex64::new(0.986809401814185, 0.161886393780112),
-    Complex64::new(-0.986809401814185, -0.161886393780112),
-    Complex64::new(0.161886393780112, -0.986809401814185),
-    Complex64::new(-0.161886393780112, 0.986809401814185),
-    Complex64::new(0.812250586585204, -0.583308652937698),
-    Complex64::new(-0.812250586585204, 0.583308652937698),
-    Complex64::new(0.583308652937698, 0.812250586585204),
-    Complex64::new(-0.583308652937698, -0.812250586585204),
-    Complex64::new(0.973644249650812, -0.228072083170886),
-    Complex64::new(-0.973644249650812, 0.228072083170886),
-    Complex64::new(0.228072083170886, 0.973644249650812),
-    Complex64::new(-0.228072083170886, -0.973644249650812),
-    Complex64::new(0.849741768000852, 0.527199134781901),
-    Complex64::new(-0.849741768000852, -0.527199134781901),
-    Complex64::new(0.527199134781901, -0.849741768000853),
-    Complex64::new(-0.527199134781901, 0.849741768000853),
-    Complex64::new(0.997925286198596, 0.0643826309298575),
-    Complex64::new(-0.997925286198596, -0.0643826309298575),
-    Complex64::new(0.0643826309298575, -0.997925286198596),
-    Complex64::new(-0.0643826309298575, 0.997925286198596),
-    Complex64::new(0.751165131909686, -0.660114342067420),
-    Complex64::new(-0.751165131909686, 0.660114342067420),
-    Complex64::new(0.660114342067420, 0.751165131909686),
-    Complex64::new(-0.660114342067420, -0.751165131909686),
-    Complex64::new(0.946600913083284, -0.322407678801070),
-    Complex64::new(-0.946600913083284, 0.322407678801070),
-    Complex64::new(0.322407678801070, 0.946600913083284),
-    Complex64::new(-0.322407678801070, -0.946600913083284),
-    Complex64::new(0.897324580705418, 0.441371268731717),
-    Complex64::new(-0.897324580705418, -0.441371268731717),
-    Complex64::new(0.441371268731717, -0.897324580705418),
-    Complex64::new(-0.441371268731717, 0.897324580705418),
-    Complex64::new(0.991310859846115, -0.131540028702883),
-    Complex64::new(-0.991310859846115, 0.131540028702883),
-    Complex64::new(0.131540028702883, 0.991310859846115),
-    Complex64::new(-0.131540028702883, -0.991310859846115),
-    Complex64::new(0.793975477554337, 0.607949784967774),
-    Complex64::new(-0.793975477554337, -0.607949784967774),
-    Complex64::new(0.607949784967774, -0.793975477554337),
-    Complex64::new(-0.607949784967774, 0.793975477554337),
-    Complex64::new(0.966190003445413, 0.257831102162159),
-    Complex64::new(-0.966190003445413, -0.257831102162159),
-    Complex64::new(0.257831102162159, -0.966190003445413),
-    Complex64::new(-0.257831102162159, 0.966190003445413),
-    Complex64::new(0.865513624090569, -0.500885382611241),
-    Complex64::new(-0.865513624090569, 0.500885382611241),
-    Complex64::new(0.500885382611241, 0.865513624090569),
-    Complex64::new(-0.500885382611241, -0.865513624090569),
-    Complex64::new(0.999995293809576, -0.00306795676296598),
-    Complex64::new(-0.999995293809576, 0.00306795676296598),
-    Complex64::new(0.00306795676296598, 0.999995293809576),
-    Complex64::new(-0.00306795676296598, -0.999995293809576),
-    Complex64::new(0.709272826438866, 0.704934080375905),
-    Complex64::new(-0.709272826438866, -0.704934080375905),
-    Complex64::new(0.704934080375905, -0.709272826438866),
-    Complex64::new(-0.704934080375905, 0.709272826438866),
-    Complex64::new(0.925049240782678, 0.379847208924051),
-    Complex64::new(-0.925049240782678, -0.379847208924051),
-    Complex64::new(0.379847208924051, -0.925049240782678),
-    Complex64::new(-0.379847208924051, 0.925049240782678),
-    Complex64::new(0.922701128333879, -0.385516053843919),
-    Complex64::new(-0.922701128333879, 0.385516053843919),
-    Complex64::new(0.385516053843919, 0.922701128333879),
-    Complex64::new(-0.385516053843919, -0.922701128333879),
-    Complex64::new(0.981379193313755, 0.192080397049892),
-    Complex64::new(-0.981379193313755, 0.192080397049892),
-    Complex64::new(0.192080397049892, -0.981379193313755),
-    Complex64::new(-0.192080397049892, 0.981379193313755),
-    Complex64::new(0.829761233794523, -0.558118531220556),
-    Complex64::new(-0.829761233794523, 0.558118531220556),
-    Complex64::new(0.558118531220556, 0.829761233794523),
-    Complex64::new(-0.558118531220556, -0.829761233794523),
-    Complex64::new(0.980182135968117, -0.198098410717954),
-    Complex64::new(-0.980182135968117, 0.198098410717954),
-    Complex64::new(0.198098410717954, 0.980182135968117),
-    Complex64::new(-0.198098410717954, -0.980182135968117),
-    Complex64::new(0.833170164701913, 0.553016705580028),
-    Complex64::new(-0.833170164701913, -0.553016705580028),
-    Complex64::new(0.553016705580028, -0.833170164701913),
-    Complex64::new(-0.553016705580028, 0.833170164701913),
-    Complex64::new(0.995480755491927, 0.0949634953296390),
-    Complex64::new(-0.995480755491927, -0.0949634953296390),
-    Complex64::new(0.0949634953296390, -0.995480755491927),
-    Complex64::new(-0.0949634953296390, 0.995480755491927),
-    Complex64::new(0.771060524261814, -0.636761861236284),
-    Complex64::new(-0.771060524261814, 0.636761861236284),
-    Complex64::new(0.636761861236284, 0.771060524261814),
-    Complex64::new(-0.636761861236284, -0.771060524261814),
-    Complex64::new(0.956045251349996, -0.293219162694259),
-    Complex64::new(-0.956045251349996, 0.293219162694259),
-    Complex64::new(0.293219162694259, 0.956045251349996),
-    Complex64::new(-0.293219162694259, -0.956045251349996),
-    Complex64::new(0.883363338665732, 0.468688822035828),
-    Complex64::new(-0.883363338665732, -0.468688822035828),
-    Complex64::new(0.468688822035828, -0.883363338665732),
-    Complex64::new(-0.468688822035828, 0.883363338665732),
-    Complex64::new(0.994879330794806, -0.101069862754828),
-    Complex64::new(-0.994879330794806, 0.101069862754828),
-    Complex64::new(0.101069862754828, 0.994879330794806),
-    Complex64::new(-0.101069862754828, -0.994879330794806),
-    Complex64::new(0.774953106594874, 0.632018735939809),
-    Complex64::new(-0.774953106594874, -0.632018735939809),
-    Complex64::new(0.632018735939809, -0.774953106594874),
-    Complex64::new(-0.632018735939809, 0.774953106594874),
-    Complex64::new(0.957826413027533, 0.287347459544730),
-    Complex64::new(-0.957826413027533, -0.287347459544730),
-    Complex64::new(0.287347459544730, -0.957826413027533),
-    Complex64::new(-0.287347459544730, 0.957826413027533),
-    Complex64::new(0.880470889052161, -0.474100214650550),
-    Complex64::new(-0.880470889052161, 0.474100214650550),
-    Complex64::new(0.474100214650550, 0.880470889052161),
-    Complex64::new(-0.474100214650550, -0.880470889052161),
-    Complex64::new(0.998941293186857, 0.0460031821309146),
-    Complex64::new(-0.998941293186857, 0.0460031821309146),
-    Complex64::new(0.0460031821309146, -0.998941293186857),
-    Complex64::new(-0.0460031821309146, 0.998941293186857),
-    Complex64::new(0.738887324460615, -0.673829000378756),
-    Complex64::new(-0.738887324460615, 0.673829000378756),
-    Complex64::new(0.673829000378756, 0.738887324460615),
-    Complex64::new(-0.673829000378756, -0.738887324460615),
-    Complex64::new(0.940506070593268, -0.339776884406827),
-    Complex64::new(-0.940506070593268, 0.339776884406827),
-    Complex64::new(0.339776884406827, 0.940506070593268),
-    Complex64::new(-0.339776884406827, -0.940506070593268),
-    Complex64::new(0.905296759318119, 0.424779681209109),
-    Complex64::new(-0.905296759318119, -0.424779681209109),
-    Complex64::new(0.424779681209109, -0.905296759318119),
-    Complex64::new(-0.424779681209109, 0.905296759318119),
-    Complex64::new(0.988721691960324, -0.149764534677322),
-    Complex64::new(-0.988721691960324, 0.149764534677322),
-    Complex64::new(0.149764534677322, 0.988721691960324),
-    Complex64::new(-0.149764534677322, 0.988721691960324),
-    Complex64::new(0.805031331142964, 0.593232295039800),
-    Complex64::new(-0.805031331142964, -0.593232295039800),
-    Complex64::new(0.593232295039800, -0.805031331142964),
-    Complex64::new(-0.593232295039800, 0.805031331142964),
-    Complex64::new(0.970772140728950, 0.240003022448741),
-    Complex64::new(-0.970772140728950, 0.240003022448741),
-    Complex64::new(0.240003022448741, -0.970772140728950),
-    Complex64::new(-0.240003022448741, 0.970772140728950),
-    Complex64::new(0.856147328375194, -0.516731799017650),
-    Complex64::new(-0.856147328375194, 0.516731799017650),
-    Complex64::new(0.516731799017650, 0.856147328375194),
-    Complex64::new(-0.516731799017650, -0.856147328375194),
-    Complex64::new(0.998640218180265, -0.0521317046802833),
-    Complex64::new(-0.998640218180265, 0.0521317046802833),
-    Complex64::new(0.0521317046802833, 0.998640218180265),
-    Complex64::new(-0.0521317046802833, 0.998640218180265),
-    Complex64::new(0.743007952135122, 0.669282588346636),
-    Complex64::new(-0.743007952135122, -0.669282588346636),
-    Complex64::new(0.669282588346636, -0.743007952135122),
-    Complex64::new(-0.669282588346636, 0.743007952135122),
-    Complex64::new(0.942573197601447, 0.333999651442009),
-    Complex64::new(-0.942573197601447, -0.333999651442009),
-    Complex64::new(0.333999651442009, -0.942573197601447),
-    Complex64::new(-0.333999651442009, 0.942573197601447),
-    Complex64::new(0.902673318237259, -0.430326481340083),
-    Complex64::new(-0.902673318237259, 0.430326481340083),
-    Complex64::new(0.430326481340083, 0.902673318237259),
-    Complex64::new(-0.430326481340083, -0.902673318237259),
-    Complex64::new(0.989622017463201, 0.143695033150294),
-    Complex64::new(-0.989622017463201, 0.143695033150294),
-    Complex64::new(0.143695033150294, -0.989622017463201),
-    Complex64::new(-0.143695033150294, 0.989622017463201),
-    Complex64::new(0.801376171723140, -0.598160706996342),
-    Complex64::new(-0.801376171723140, 0.598160706996342),
-    Complex64::new(0.598160706996342, 0.801376171723140),
-    Complex64::new(-0.598160706996342, -0.801376171723140),
-    Complex64::new(0.969281235356549, -0.245955050335795),
-    Complex64::new(-0.969281235356549, 0.245955050335795),
-    Complex64::new(0.245955050335795, 0.969281235356549),
-    Complex64::new(-0.245955050335795, -0.969281235356549),
-    Complex64::new(0.859301818357008, 0.511468850437970),
-    Complex64::new(-0.859301818357008, -0.511468850437970),
-    Complex64::new(0.511468850437970, -0.859301818357008),
-    Complex64::new(-0.511468850437970, 0.859301818357008),
-    Complex64::new(0.999769405351215, 0.0214740802754695),
-    Complex64::new(-0.999769405351215, 0.0214740802754695),
-    Complex64::new(0.0214740802754695, -0.999769405351215),
-    Complex64::new(-0.0214740802754695, 0.999769405351215),
-    Complex64::new(0.722128193929215, -0.691759258364158),
-    Complex64::new(-0.722128193929215, 0.691759258364158),
-    Complex64::new(0.691759258364158, 0.722128193929215),
-    Complex64::new(-0.691759258364158, -0.722128193929215),
-    Complex64::new(0.931884265581668, -0.362755724367397),
-    Complex64::new(-0.931884265581668, 0.362755724367397),
-    Complex64::new(0.362755724367397, 0.931884265581668),
-    Complex64::new(-0.362755724367397, -0.931884265581668),
-    Complex64::new(0.915448716088268, 0.402434650859418),
-    Complex64::new(-0.915448716088268, -0.402434650859418),
-    Complex64::new(0.402434650859418, -0.915448716088268),
-    Complex64::new(-0.402434650859418, 0.915448716088268),
-    Complex64::new(0.984748501801904, -0.173983873387464),
-    Complex64::new(-0.984748501801904, 0.173983873387464),
-    Complex64::new(0.173983873387464, 0.984748501801904),
-    Complex64::new(-0.173983873387464, -0.984748501801904),
-    Complex64::new(0.819347520076797, 0.573297166698042),
-    Complex64::new(-0.819347520076797, -0.573297166698042),
-    Complex64::new(0.573297166698042, -0.819347520076797),
-    Complex64::new(-0.573297166698042, 0.819347520076797),
-    Complex64::new(0.976369731330021, 0.216106797076220),
-    Complex64::new(-0.976369731330021, 0.216106797076220),
-    Complex64::new(0.216106797076220, -0.976369731330021),
-    Complex64::new(-0.216106797076220, 0.976369731330021),
-    Complex64::new(0.843208239641845, -0.537587076295646),
-    Complex64::new(-0.843208239641845, 0.537587076295646),
-    Complex64::new(0.537587076295646, 0.843208239641845),
-    Complex64::new(-0.537587076295646, -0.843208239641845),
-    Complex64::new(0.997060070339483, -0.0766238613920315),
-    Complex64::new(-0.997060070339483, 0.0766238613920315),
-    Complex64::new(0.0766238613920315, 0.997060070339483),
-    Complex64::new(-0.0766238613920315, -0.997060070339483),
-    Complex64::new(0.759209188978388, 0.650846684996381),
-    Complex64::new(-0.759209188978388, -0.650846684996381),
-    Complex64::new(0.650846684996381, -0.759209188978388),
-    Complex64::new(-0.650846684996381, 0.759209188978388),
-    Complex64::new(0.950486073949482, 0.310767152749612),
-    Complex64::new(-0.950486073949482, 0.310767152749612),
-    Complex64::new(0.310767152749612, -0.950486073949482),
-    Complex64::new(-0.310767152749612, 0.950486073949482),
-    Complex64::new(0.891840709392343, -0.452349587233771),
-    Complex64::new(-0.891840709392343, 0.452349587233771),
-    Complex64::new(0.452349587233771, 0.891840709392343),
-    Complex64::new(-0.452349587233771, -0.891840709392343),
-    Complex64::new(0.992850414459865, 0.119365214810991),
-    Complex64::new(-0.992850414459865, 0.119365214810991),
-    Complex64::new(0.119365214810991, -0.992850414459865),
-    Complex64::new(-0.119365214810991, 0.992850414459865),
-    Complex64::new(0.786455213599086, -0.617647307937804),
-    Complex64::new(-0.786455213599086, 0.617647307937804),
-    Complex64::new(0.617647307937804, 0.786455213599086),
-    Complex64::new(-0.617647307937804, -0.786455213599086),
-    Complex64::new(0.962953266873684, -0.269668325572915),
-    Complex64::new(-0.962953266873684, 0.269668325572915),
-    Complex64::new(0.269668325572915, 0.962953266873684),
-    Complex64::new(-0.269668325572915, -0.962953266873684),
-    Complex64::new(0.871595086655951, 0.490226483288291),
-    Complex64::new(-0.871595086655951, -0.490226483288291),
-    Complex64::new(0.490226483288291, -0.871595086655951),
-    Complex64::new(-0.490226483288291, 0.871595086655951),
-    Complex64::new(0.999618822495179, -0.0276081457789657),
-    Complex64::new(-0.999618822495179, 0.0276081457789657),
-    Complex64::new(0.0276081457789657, 0.999618822495179),
-    Complex64::new(-0.0276081457789657, -0.999618822495179),
-    Complex64::new(0.726359155084346, 0.687315340891759),
-    Complex64::new(-0.726359155084346, -0.687315340891759),
-    Complex64::new(0.687315340891759, -0.726359155084346),
-    Complex64::new(-0.687315340891759, 0.726359155084346),
-    Complex64::new(0.934092550404259, 0.357030961233430),
-    Complex64::new(-0.934092550404259, -0.357030961233430),
-    Complex64::new(0.357030961233430, -0.934092550404259),
-    Complex64::new(-0.357030961233430, 0.934092550404259),
-    Complex64::new(0.912962190428398, -0.408044162864979),
-    Complex64::new(-0.912962190428398, 0.408044162864979),
-    Complex64::new(0.408044162864979, 0.912962190428398),
-    Complex64::new(-0.408044162864979, -0.912962190428398),
-    Complex64::new(0.985797509167567, 0.167938294974731),
-    Complex64::new(-0.985797509167567, 0.167938294974731),
-    Complex64::new(0.167938294974731, -0.985797509167567),
-    Complex64::new(-0.167938294974731, 0.985797509167567),
-    Complex64::new(0.815814410806734, -0.578313796411656),
-    Complex64::new(-0.815814410806734, 0.578313796411656),
-    Complex64::new(0.578313796411656, 0.815814410806734),
-    Complex64::new(-0.578313796411656, -0.815814410806734),
-    Complex64::new(0.975025345066994, -0.222093620973204),
-    Complex64::new(-0.975025345066994, 0.222093620973204),
-    Complex64::new(0.222093620973204, 0.975025345066994),
-    Complex64::new(-0.222093620973204, -0.975025345066994),
-    Complex64::new(0.846490938774052, 0.532403127877198),
-    Complex64::new(-0.846490938774052, -0.532403127877198),
-    Complex64::new(0.532403127877198, -0.846490938774052),
-    Complex64::new(-0.532403127877198, 0.846490938774052),
-    Complex64::new(0.997511456140303, 0.0705045733896139),
-    Complex64::new(-0.997511456140303, 0.0705045733896139),
-    Complex64::new(0.0705045733896139, -0.997511456140303),
-    Complex64::new(-0.0705045733896139, 0.997511456140303),
-    Complex64::new(0.755201376896537, -0.655492852999615),
-    Complex64::new(-0.755201376896537, 0.655492852999615),
-    Complex64::new(0.655492852999615, 0.755201376896537),
-    Complex64::new(-0.655492852999615, -0.755201376896537),
-    Complex64::new(0.948561349915730, -0.316593375556166),
-    Complex64::new(-0.948561349915730, 0.316593375556166),
-    Complex64::new(0.316593375556166, 0.948561349915730),
-    Complex64::new(-0.316593375556166, -0.948561349915730),
-    Complex64::new(0.894599485631383, 0.446868840162374),
-    Complex64::new(-0.894599485631383, -0.446868840162374),
-    Complex64::new(0.446868840162374, -0.894599485631383),
-    Complex64::new(-0.446868840162374, 0.894599485631383),
-    Complex64::new(0.992099313142192, -0.125454983411546),
-    Complex64::new(-0.992099313142192, 0.125454983411546),
-    Complex64::new(0.125454983411546, 0.992099313142192),
-    Complex64::new(-0.125454983411546, -0.992099313142192),
-    Complex64::new(0.790230221437310, 0.612810082429410),
-    Complex64::new(-0.790230221437310, -0.612810082429410),
-    Complex64::new(0.612810082429410, -0.790230221437310),
-    Complex64::new(-0.612810082429410, 0.790230221437310),
-    Complex64::new(0.964589793289813, 0.263754678974831),
-    Complex64::new(-0.964589793289813, 0.263754678974831),
-    Complex64::new(0.263754678974831, -0.964589793289813),
-    Complex64::new(-0.263754678974831, 0.964589793289813),
-    Complex64::new(0.868570705971341, -0.495565261825772),
-    Complex64::new(-0.868570705971341, 0.495565261825772),
-    Complex64::new(0.495565261825772, 0.868570705971341),
-    Complex64::new(-0.495565261825772, -0.868570705971341),
-    Complex64::new(0.999957644551964, 0.00920375478205982),
-    Complex64::new(-0.999957644551964, 0.00920375478205982),
-    Complex64::new(0.00920375478205982, -0.999957644551964),
-    Complex64::new(-0.00920375478205982, 0.999957644551964),
-    Complex64::new(0.713584868780794, -0.700568793943248),
-    Complex64::new(-0.713584868780794, 0.700568793943248),
-    Complex64::new(0.700568793943248, 0.713584868780794),
-    Complex64::new(-0.700568793943248, -0.713584868780794),
-    Complex64::new(0.927362525650401, -0.374164062971458),
-    Complex64::new(-0.927362525650401, 0.374164062971458),
-    Complex64::new(0.374164062971458, 0.927362525650401),
-    Complex64::new(-0.374164062971458, -0.927362525650401),
-    Complex64::new(0.920318276709110, 0.391170384302254),
-    Complex64::new(-0.920318276709110, -0.391170384302254),
-    Complex64::new(0.391170384302254, -0.920318276709111),
-    Complex64::new(-0.391170384302254, 0.920318276709111),
-    Complex64::new(0.982539302287441, 0.186055151663447),
-    Complex64::new(-0.982539302287441, 0.186055151663447),
-    Complex64::new(0.186055151663447, -0.982539302287441),
-    Complex64::new(-0.186055151663447, 0.982539302287441),
-    Complex64::new(0.826321062845664, 0.563199344013834),
-    Complex64::new(-0.826321062845664, -0.563199344013834),
-    Complex64::new(0.563199344013834, -0.826321062845664),
-    Complex64::new(-0.563199344013834, 0.826321062845664),
-    Complex64::new(0.978948175319062, 0.204108966092817),
-    Complex64::new(-0.978948175319062, 0.204108966092817),
-    Complex64::new(0.204108966092817, -0.978948175319062),
-    Complex64::new(-0.204108966092817, 0.978948175319062),
-    Complex64::new(0.836547727223512, 0.547894059173100),
-    Complex64::new(-0.836547727223512, -0.547894059173100),
-    Complex64::new(0.547894059173100, -0.836547727223512),
-    Complex64::new(-0.547894059173100, 0.836547727223512),
-    Complex64::new(0.547894059173100, 0.836547727223512),
-    Complex64::new(-0.547894059173100, -0.836547727223512),
-    Complex64::new(0.996044700901252, 0.0888535525825246),
-    Complex64::new(-0.996044700901252, 0.0888535525825246),
-    Complex64::new(0.0888535525825246, 0.996044700901252),
-    Complex64::new(-0.0888535525825246, -0.996044700901252),
-    Complex64::new(0.767138911935820, 0.641481012808583),
-    Complex64::new(-0.767138911935820, -0.641481012808583),
-    Complex64::new(0.641481012808583, -0.767138911935820),
-    Complex64::new(-0.641481012808583, 0.767138911935820),
-    Complex64::new(0.954228095109106, 0.299079826308040),
-    Complex64::new(-0.954228095109106, 0.299079826308040),
-    Complex64::new(0.299079826308040, -0.954228095109106),
-    Complex64::new(-0.299079826308040, 0.954228095109106),
-    Complex64::new(0.886222530148881, -0.463259783551860),
-    Complex64::new(-0.886222530148881, 0.463259783551860),
-    Complex64::new(0.463259783551860, 0.886222530148881),
-    Complex64::new(-0.463259783551860, -0.886222530148881),
-    Complex64::new(0.994240449453188, 0.107172424956809),
-    Complex64::new(-0.994240449453188, 0.107172424956809),
-    Complex64::new(0.107172424956809, -0.994240449453188),
-    Complex64::new(-0.107172424956809, 0.994240449453188),
-    Complex64::new(0.778816512381476, -0.627251815495144),
-    Complex64::new(-0.778816512381476, 0.627251815495144),
-    Complex64::new(0.627251815495144, 0.778816512381476),
-    Complex64::new(-0.627251815495144, -0.778816512381476),
-    Complex64::new(0.959571513081985, -0.281464937925758),
-    Complex64::new(-0.959571513081985, 0.281464937925758),
-    Complex64::new(0.281464937925758, 0.959571513081985),
-    Complex64::new(-0.281464937925758, -0.959571513081985),
-    Complex64::new(0.877545290207261, 0.479493757660153),
-    Complex64::new(-0.877545290207261, -0.479493757660153),
-    Complex64::new(0.479493757660153, -0.877545290207261),
-    Complex64::new(-0.479493757660153, 0.877545290207261),
-    Complex64::new(0.999204758618364, -0.0398729275877398),
-    Complex64::new(-0.999204758618364, 0.0398729275877398),
-    Complex64::new(0.0398729275877398, 0.999204758618364),
-    Complex64::new(-0.0398729275877398, -0.999204758618364),
-    Complex64::new(0.734738878095963, 0.678350043129862),
-    Complex64::new(-0.734738878095963, -0.678350043129862),
-    Complex64::new(0.678350043129861, -0.734738878095963),
-    Complex64::new(-0.678350043129861, 0.734738878095963),
-    Complex64::new(0.938403534063108, 0.345541324963989),
-    Complex64::new(-0.938403534063108, -0.345541324963989),
-    Complex64::new(0.345541324963989, -0.938403534063108),
-    Complex64::new(-0.345541324963989, 0.938403534063108),
-    Complex64::new(0.907886116487666, -0.419216888363224),
-    Complex64::new(-0.907886116487666, 0.419216888363224),
-    Complex64::new(0.419216888363224, 0.907886116487666),
-    Complex64::new(-0.419216888363224, -0.907886116487666),
-    Complex64::new(0.987784141644572, 0.155828397654265),
-    Complex64::new(-0.987784141644572, 0.155828397654265),
-    Complex64::new(0.155828397654265, -0.987784141644572),
-    Complex64::new(-0.155828397654265, 0.987784141644572),
-    Complex64::new(0.808656181588175, -0.588281548222645),
-    Complex64::new(-0.808656181588175, 0.588281548222645),
-    Complex64::new(0.588281548222645, 0.808656181588175),
-    Complex64::new(-0.588281548222645, -0.808656181588175),
-    Complex64::new(0.972226497078936, -0.234041958583543),
-    Complex64::new(-0.972226497078936, 0.234041958583543),
-    Complex64::new(0.234041958583543, 0.972226497078936),
-    Complex64::new(-0.234041958583543, -0.972226497078936),
-    Complex64::new(0.852960604930364, 0.521975292937154),
-    Complex64::new(-0.852960604930364, -0.521975292937154),
-    Complex64::new(0.521975292937154, -0.852960604930364),
-    Complex64::new(-0.521975292937154, 0.852960604930364),
-    Complex64::new(0.998301544933893, 0.0582582645004358),
-    Complex64::new(-0.998301544933893, -0.0582582645004358),
-    Complex64::new(0.0582582645004358, -0.998301544933893),
-    Complex64::new(-0.0582582645004358, 0.998301544933893),
-    Complex64::new(0.747100605980180, -0.664710978203345),
-    Complex64::new(-0.747100605980180, 0.664710978203345),
-    Complex64::new(0.664710978203345, 0.747100605980180),
-    Complex64::new(-0.664710978203345, -0.747100605980180),
-    Complex64::new(0.944604837261480, -0.328209843579092),
-    Complex64::new(-0.944604837261480, 0.328209843579092),
-    Complex64::new(0.328209843579092, 0.944604837261480),
-    Complex64::new(-0.328209843579092, -0.944604837261480),
-    Complex64::new(0.900015892016160, 0.435857079922255),
-    Complex64::new(-0.900015892016160, -0.435857079922255),
-    Complex64::new(0.435857079922256, -0.900015892016160),
-    Complex64::new(-0.435857079922256, 0.900015892016160),
-    Complex64::new(0.990485084256457, -0.137620121586486),
-    Complex64::new(-0.990485084256457, 0.137620121586486),
-    Complex64::new(0.137620121586486, 0.990485084256457),
-    Complex64::new(-0.137620121586486, -0.990485084256457),
-    Complex64::new(0.797690840943391, 0.603066598540348),
-    Complex64::new(-0.797690840943391, -0.603066598540348),
-    Complex64::new(0.603066598540348, -0.797690840943391),
-    Complex64::new(-0.603066598540348, 0.797690840943391),
-    Complex64::new(0.967753837093475, 0.251897818154217),
-    Complex64::new(-0.967753837093475, 0.251897818154217),
-    Complex64::new(0.251897818154217, -0.967753837093475),
-    Complex64::new(-0.251897818154217, 0.967753837093475),
-    Complex64::new(0.862423956111041, -0.506186645345155),
-    Complex64::new(-0.862423956111041, 0.506186645345155),
-    Complex64::new(0.506186645345155, 0.862423956111041),
-    Complex64::new(-0.506186645345155, -0.862423956111041),
-    Complex64::new(0.999882347454213, -0.0153392062849881),
-    Complex64::new(-0.999882347454213, 0.0153392062849881),
-    Complex64::new(0.0153392062849881, 0.999882347454213),
-    Complex64::new(-0.0153392062849881, -0.999882347454213),
-    Complex64::new(0.717870045055732, 0.696177131491463),
-    Complex64::new(-0.717870045055732, -0.696177131491463),
-    Complex64::new(0.696177131491463, -0.717870045055732),
-    Complex64::new(-0.696177131491463, 0.717870045055732),
-    Complex64::new(0.929640895843181, 0.368466829953372),
-    Complex64::new(-0.929640895843181, -0.368466829953372),
-    Complex64::new(0.368466829953372, -0.929640895843181),
-    Complex64::new(-0.368466829953372, 0.929640895843181),
-    Complex64::new(0.917900775621390, -0.396809987416710),
-    Complex64::new(-0.917900775621390, 0.396809987416710),
-    Complex64::new(0.396809987416710, 0.917900775621390),
-    Complex64::new(-0.396809987416710, -0.917900775621390),
-    Complex64::new(0.983662419211730, 0.180022901405700),
-    Complex64::new(-0.983662419211730, 0.180022901405700),
-    Complex64::new(0.180022901405700, -0.983662419211730),
-    Complex64::new(-0.180022901405700, 0.983662419211730),
-    Complex64::new(0.822849781375826, -0.568258952670132),
-    Complex64::new(-0.822849781375826, 0.568258952670132),
-    Complex64::new(0.568258952670132, 0.822849781375826),
-    Complex64::new(-0.568258952670132, -0.822849781375826),
-    Complex64::new(0.977677357824510, -0.210111836880470),
-    Complex64::new(-0.977677357824510, 0.210111836880470),
-    Complex64::new(0.210111836880470, 0.977677357824510),
-    Complex64::new(-0.210111836880470, -0.977677357824510),
-    Complex64::new(0.839893794196000, 0.542750784864516),
-    Complex64::new(-0.839893794196000, -0.542750784864516),
-    Complex64::new(0.542750784864516, -0.839893794196000),
-    Complex64::new(-0.542750784864516, 0.839893794196000),
-    Complex64::new(0.996571145790555, 0.0827402645493757),
-    Complex64::new(-0.996571145790555, -0.0827402645493757),
-    Complex64::new(0.0827402645493757, -0.996571145790555),
-    Complex64::new(-0.0827402645493757, 0.996571145790555),
-    Complex64::new(0.763188417263381, -0.646176012983316),
-    Complex64::new(-0.763188417263381, 0.646176012983316),
-    Complex64::new(0.646176012983316, 0.763188417263381),
-    Complex64::new(-0.646176012983316, -0.763188417263381),
-    Complex64::new(0.952375012719766, -0.304929229735402),
-    Complex64::new(-0.952375012719766, 0.304929229735402),
-    Complex64::new(0.304929229735402, 0.952375012719766),
-    Complex64::new(-0.304929229735402, -0.952375012719766),
-    Complex64::new(0.889048355854665, 0.457813303598877),
-    Complex64::new(-0.889048355854665, -0.457813303598877),
-    Complex64::new(0.457813303598877, -0.889048355854665),
-    Complex64::new(-0.457813303598877, 0.889048355854665),
-    Complex64::new(0.993564135520595, -0.113270952177564),
-    Complex64::new(-0.993564135520595, 0.113270952177564),
-    Complex64::new(0.113270952177564, 0.993564135520595),
-    Complex64::new(-0.113270952177564, -0.993564135520595),
-    Complex64::new(0.782650596166576, 0.622461279374150),
-    Complex64::new(-0.782650596166576, -0.622461279374150),
-    Complex64::new(0.622461279374150, -0.782650596166576),
-    Complex64::new(-0.622461279374150, 0.782650596166576),
-    Complex64::new(0.961280485811321, 0.275571819310958),
-    Complex64::new(-0.961280485811321, 0.275571819310958),
-    Complex64::new(0.275571819310958, -0.961280485811321),
-    Complex64::new(-0.275571819310958, 0.961280485811321),
-    Complex64::new(0.874586652278176, -0.484869248000791),
-    Complex64::new(-0.874586652278176, 0.484869248000791),
-    Complex64::new(0.484869248000791, 0.874586652278176),
-    Complex64::new(-0.484869248000791, -0.874586652278176),
-    Complex64::new(0.999430604555462, 0.0337411718513776),
-    Complex64::new(-0.999430604555462, 0.0337411718513776),
-    Complex64::new(0.0337411718513776, -0.999430604555462),
-    Complex64::new(-0.0337411718513776, 0.999430604555462),
-    Complex64::new(0.730562769227828, -0.682845546385248),
-    Complex64::new(-0.730562769227828, 0.682845546385248),
-    Complex64::new(0.682845546385248, 0.730562769227828),
-    Complex64::new(-0.682845546385248, -0.730562769227828),
-    Complex64::new(0.936265667170278, -0.351292756085567),
-    Complex64::new(-0.936265667170278, 0.351292756085567),
-    Complex64::new(0.351292756085567, 0.936265667170278),
-    Complex64::new(-0.351292756085567, -0.936265667170278),
-    Complex64::new(0.910441292258067, 0.413638312238435),
-    Complex64::new(-0.910441292258067, -0.413638312238435),
-    Complex64::new(0.413638312238435, -0.910441292258067),
-    Complex64::new(-0.413638312238435, 0.910441292258067),
-    Complex64::new(0.986809401814185, -0.161886393780112),
-    Complex64::new(-0.986809401814185, 0.161886393780112),
-    Complex64::new(0.161886393780112, 0.986809401814185),
-    Complex64::new(-0.161886393780112, -0.986809401814185),
-    Complex64::new(0.812250586585204, 0.583308652937698),
-    Complex64::new(-0.812250586585204, -0.583308652937698),
-    Complex64::new(0.583308652937698, -0.812250586585204),
-    Complex64::new(-0.583308652937698, 0.812250586585204),
-    Complex64::new(0.973644249650812, 0.228072083170886),
-    Complex64::new(-0.973644249650812, 0.228072083170886),
-    Complex64::new(0.228072083170886, -0.973644249650812),
-    Complex64::new(-0.228072083170886, 0.973644249650812),
-    Complex64::new(0.849741768000852, -0.527199134781901),
-    Complex64::new(-0.849741768000852, 0.527199134781901),
-    Complex64::new(0.527199134781901, 0.849741768000853),
-    Complex64::new(-0.527199134781901, -0.849741768000853),
-    Complex64::new(0.997925286198596, -0.0643826309298575),
-    Complex64::new(-0.997925286198596, 0.0643826309298575),
-    Complex64::new(0.0643826309298575, 0.997925286198596),
-    Complex64::new(-0.0643826309298575, 0.997925286198596),
-    Complex64::new(0.751165131909686, 0.660114342067420),
-    Complex64::new(-0.751165131909686, -0.660114342067420),
-    Complex64::new(0.660114342067420, -0.751165131909686),
-    Complex64::new(-0.660114342067420, 0.751165131909686),
-    Complex64::new(0.946600913083284, 0.322407678801070),
-    Complex64::new(-0.946600913083284, -0.322407678801070),
-    Complex64::new(0.322407678801070, 0.946600913083284),
-    Complex64::new(-0.322407678801070, 0.946600913083284),
-    Complex64::new(0.897324580705418, -0.441371268731717),
-    Complex64::new(-0.897324580705418, 0.441371268731717),
-    Complex64::new(0.441371268731717, 0.897324580705418),
-    Complex64::new(-0.441371268731717, -0.897324580705418),
-    Complex64::new(0.991310859846115, 0.131540028702883),
-    Complex64::new(-0.991310859846115, -0.131540028702883),
-    Complex64::new(0.131540028702883, -0.991310859846115),
-    Complex64::new(-0.131540028702883, 0.991310859846115), 
-    Complex64::new(0.793975477554337, -0.607949784967774),
-    Complex64::new(-0.793975477554337, 0.607949784967774),
-    Complex64::new(0.607949784967774, 0.793975477554337),
-    Complex64::new(-0.607949784967774, -0.793975477554337),
-    Complex64::new(0.966190003445413, -0.257831102162159),
-    Complex64::new(-0.966190003445413, 0.257831102162159),
-    Complex64::new(0.257831102162159, 0.966190003445413),
-    Complex64::new(-0.257831102162159, -0.966190003445413),
-    Complex64::new(0.865513624090569, 0.500885382611241),
-    Complex64::new(-0.865513624090569, -0.500885382611241),
-    Complex64::new(0.500885382611241, -0.865513624090569),
-    Complex64::new(-0.500885382611241, 0.865513624090569),
-    Complex64::new(0.793975477554337, -0.607949784967774),
-    Complex64::new(-0.793975477554337, 0.607949784967774),
-    Complex64::new(0.607949784967774, 0.793975477554337),
-    Complex64::new(-0.607949784967774, -0.793975477554337),
-    Complex64::new(0.966190003445413, -0.257831102162159),
-    Complex64::new(-0.966190003445413, 0.257831102162159),
-    Complex64::new(0.257831102162159, 0.966190003445413),
-    Complex64::new(-0.257831102162159, -0.966190003445413),
-];
-*/
+include!(concat!(env!("OUT_DIR"), "/fft_roots.rs"));
 
 /// Tüm kökleri içeren sözlük
 pub fn get_roots_dict() -> std::collections::HashMap<usize, &'static [Complex64]> {
@@ -2112,8 +18,7 @@ pub fn get_roots_dict() -> std::collections::HashMap<usize, &'static [Complex64]
     map.insert(64, &PHI128_ROOTS[..]);
     map.insert(128, &PHI256_ROOTS[..]);
     map.insert(256, &PHI512_ROOTS[..]);
-    // Şimdilik 256'ya kadar - daha sonra 512 ve 1024 eklenecek
-    // map.insert(512, &PHI1024_ROOTS[..]);
-    // map.insert(1024, &PHI2048_ROOTS[..]);
+    map.insert(512, &PHI1024_ROOTS[..]);
+    map.insert(1024, &PHI2048_ROOTS[..]);
     map
 }
\ No newline at end of file
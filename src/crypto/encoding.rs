@@ -1,70 +1,212 @@
 // Falcon için imza sıkıştırma ve açma fonksiyonları (encoding.py'den çevrildi)
 
-pub fn compress(v: &[i32], slen: usize) -> Option<Vec<u8>> {
-    let mut u = String::new();
+/// Writes bit strings, most significant bit first, into a byte buffer
+#[derive(Clone, Debug, Default)]
+pub struct BitWriter {
+    buf: Vec<u8>,
+    acc: u32,
+    acc_len: u32,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the `nbits` low bits of `x` (at most 24)
+    pub fn write(&mut self, x: u32, nbits: u32) {
+        debug_assert!(nbits <= 24);
+        self.acc = (self.acc << nbits) | (x & ((1 << nbits) - 1));
+        self.acc_len += nbits;
+        while self.acc_len >= 8 {
+            self.acc_len -= 8;
+            self.buf.push((self.acc >> self.acc_len) as u8);
+        }
+        self.acc &= (1 << self.acc_len) - 1;
+    }
+
+    /// Number of bits written so far
+    pub fn bit_len(&self) -> usize {
+        8 * self.buf.len() + self.acc_len as usize
+    }
+
+    /// Returns the written bytes, the last one being padded with zeros
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        if self.acc_len > 0 {
+            self.buf.push((self.acc << (8 - self.acc_len)) as u8);
+        }
+        self.buf
+    }
+}
+
+/// Reads bit strings, most significant bit first, from a byte slice
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Reads `nbits` bits (at most 24), or returns None past the end of the data
+    pub fn read(&mut self, nbits: u32) -> Option<u32> {
+        debug_assert!(nbits <= 24);
+        if self.pos + nbits as usize > 8 * self.data.len() {
+            return None;
+        }
+        let mut x = 0u32;
+        for _ in 0..nbits {
+            let bit = (self.data[self.pos >> 3] >> (7 - (self.pos & 7))) & 1;
+            x = (x << 1) | bit as u32;
+            self.pos += 1;
+        }
+        Some(x)
+    }
+
+    /// Checks that all the bits left to read are zero
+    pub fn rest_is_zero(&self) -> bool {
+        let byte = self.pos >> 3;
+        if self.pos & 7 != 0 && self.data[byte] & (0xFF >> (self.pos & 7)) != 0 {
+            return false;
+        }
+        self.data[self.pos.div_ceil(8)..].iter().all(|&b| b == 0)
+    }
+}
+
+/// Packs small integers as `bits`-bit two's complement values
+///
+/// Returns None if a coefficient lies outside [-(2^(bits-1) - 1), 2^(bits-1) - 1];
+/// the value -2^(bits-1) is not allowed so that the encoding stays symmetric.
+pub fn trim_i8_encode(v: &[i64], bits: u32) -> Option<Vec<u8>> {
+    let max = (1i64 << (bits - 1)) - 1;
+    let mut w = BitWriter::new();
+    for &coef in v {
+        if coef < -max || coef > max {
+            return None;
+        }
+        w.write(coef as u32, bits);
+    }
+    Some(w.into_bytes())
+}
+
+/// Unpacks n integers encoded by `trim_i8_encode`, which must fill `x` exactly
+pub fn trim_i8_decode(x: &[u8], bits: u32, n: usize) -> Option<Vec<i64>> {
+    if x.len() != (bits as usize * n).div_ceil(8) {
+        return None;
+    }
+    let mut r = BitReader::new(x);
+    let mut v = Vec::with_capacity(n);
+    for _ in 0..n {
+        let w = r.read(bits)?;
+        // Sign extension
+        let coef = ((w << (32 - bits)) as i32 >> (32 - bits)) as i64;
+        if coef == -(1 << (bits - 1)) {
+            return None;
+        }
+        v.push(coef);
+    }
+    if !r.rest_is_zero() {
+        return None;
+    }
+    Some(v)
+}
+
+/// Packs s2 at `bits` bits per coefficient (two's complement) for the CT
+/// signature format, under the same range rule as `trim_i8_encode`
+pub fn trim_i16_encode(v: &[i32], bits: u32) -> Option<Vec<u8>> {
+    let max = (1i32 << (bits - 1)) - 1;
+    let mut w = BitWriter::new();
     for &coef in v {
-        // Encode the sign
-        let s = if coef < 0 { "1" } else { "0" };
-        // Encode the low bits
-        let low = format!("{:07b}", (coef.abs() % (1 << 7)));
-        // Encode the high bits
-        let high = "0".repeat((coef.abs() >> 7) as usize) + "1";
-        u += s;
-        u += &low;
-        u += &high;
-    }
-    if u.len() > 8 * slen {
+        if coef < -max || coef > max {
+            return None;
+        }
+        w.write(coef as u32, bits);
+    }
+    Some(w.into_bytes())
+}
+
+/// Unpacks n coefficients encoded by `trim_i16_encode`
+///
+/// The coefficients are read without branching on their values: the
+/// forbidden value -2^(bits-1) is only reported once the whole input is read.
+pub fn trim_i16_decode(x: &[u8], bits: u32, n: usize) -> Option<Vec<i32>> {
+    if x.len() != (bits as usize * n).div_ceil(8) {
         return None;
     }
-    u += &"0".repeat(8 * slen - u.len());
-    let mut w = Vec::with_capacity(slen);
-    for i in 0..(u.len() / 8) {
-        let byte = u[8 * i..8 * i + 8].chars().collect::<String>();
-        w.push(u8::from_str_radix(&byte, 2).unwrap());
+    let mut r = BitReader::new(x);
+    let mut v = Vec::with_capacity(n);
+    let mut bad = 0u32;
+    for _ in 0..n {
+        let w = r.read(bits)?;
+        bad |= (w == 1 << (bits - 1)) as u32;
+        v.push((w << (32 - bits)) as i32 >> (32 - bits));
+    }
+    if bad != 0 || !r.rest_is_zero() {
+        return None;
     }
-    Some(w)
+    Some(v)
 }
 
+/// Compresses s2: each coefficient is a sign bit, its 7 low bits and its high
+/// bits in unary. Returns None if a coefficient is out of range (|x| >= 2048)
+/// or if the encoding does not fit in `slen` bytes; otherwise the output is
+/// zero-padded to exactly `slen` bytes.
+pub fn compress(v: &[i32], slen: usize) -> Option<Vec<u8>> {
+    let mut w = BitWriter::new();
+    for &coef in v {
+        let a = coef.unsigned_abs();
+        if a >= 2048 {
+            return None;
+        }
+        // Sign and low bits
+        w.write(((coef < 0) as u32) << 7 | (a & 0x7F), 8);
+        // High bits: (a >> 7) zeros then a one
+        w.write(1, (a >> 7) + 1);
+        if w.bit_len() > 8 * slen {
+            return None;
+        }
+    }
+    let mut u = w.into_bytes();
+    u.resize(slen, 0);
+    Some(u)
+}
+
+/// Decompresses n coefficients from at most `slen` bytes
+///
+/// The encoding must be canonical: no -0, no coefficient of absolute value
+/// 2048 or more, and all the bits after the last coefficient set to zero.
 pub fn decompress(x: &[u8], slen: usize, n: usize) -> Option<Vec<i32>> {
     if x.len() > slen {
         return None;
     }
-    let mut u = String::new();
-    for &elt in x {
-        u += &format!("{:08b}", elt);
-    }
-    let mut u = u;
-    // Remove the last bits
-    while u.ends_with('0') && !u.is_empty() {
-        u.pop();
-    }
+    let mut r = BitReader::new(x);
     let mut v = Vec::with_capacity(n);
-    let mut idx = 0;
-    while idx < u.len() && v.len() < n {
-        // Recover the sign
-        let sign = if &u[idx..idx + 1] == "1" { -1 } else { 1 };
-        // Recover the 7 low bits
-        let low = i32::from_str_radix(&u[idx + 1..idx + 8], 2).ok()?;
-        let mut i = idx + 8;
+    for _ in 0..n {
+        let b = r.read(8)?;
+        let low = (b & 0x7F) as i32;
         let mut high = 0;
-        while i < u.len() && &u[i..i + 1] == "0" {
-            i += 1;
+        while r.read(1)? == 0 {
             high += 1;
+            if high > 15 {
+                return None;
+            }
         }
-        if i >= u.len() {
-            return None;
-        }
-        // Compute coef
-        let coef = sign * (low + (high << 7));
+        let coef = low + (high << 7);
         // Enforce a unique encoding for coef = 0
-        if coef == 0 && sign == -1 {
-            return None;
+        if b >> 7 == 1 {
+            if coef == 0 {
+                return None;
+            }
+            v.push(-coef);
+        } else {
+            v.push(coef);
         }
-        v.push(coef);
-        idx = i + 1;
     }
-    if v.len() != n {
+    if !r.rest_is_zero() {
         return None;
     }
     Some(v)
-} 
\ No newline at end of file
+}
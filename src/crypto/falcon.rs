@@ -1,10 +1,82 @@
 // Falcon ana parametreleri ve anahtar yapıları (falcon.py'den çevrildi)
 
 use std::collections::HashMap;
+use std::fmt;
+use rand::RngCore;
+use crate::crypto::encoding::{
+    compress, decompress, trim_i16_decode, trim_i16_encode, trim_i8_decode, trim_i8_encode, BitReader, BitWriter,
+};
+use crate::crypto::ntrugen::{gs_norm, ntru_gen};
+use crate::math::fft::{fft, ifft, add_fft, mul_fft};
+use crate::math::fpr::{Cplx, FloatOps, Real};
+use crate::math::ffsampling::{gram, ffldl_fft, normalize_tree, ffsampling_fft, ffnp_fft, LdlTree, MatrixFft};
+use crate::math::ntt::{div_zq, mul_zq, sub_zq};
+use crate::math::samplerz::MAX_SIGMA;
+use crate::utils::common::{Q, sqnorm};
+use crate::utils::shake::Shake256;
 
 pub const HEAD_LEN: usize = 1;
 pub const SALT_LEN: usize = 40;
 pub const SEED_LEN: usize = 56;
+/// Header bit set on deterministic signatures (0xBA for a compressed
+/// signature of degree 1024, as in Falcon-DET1024)
+///
+/// Only `PublicKey::verify_deterministic` accepts it, and that method requires
+/// it, so that flipping the bit never gives another valid signature.
+pub const DET_HEADER_BIT: u8 = 0x80;
+/// Version of the fixed salt of deterministic signatures, sent in place of
+/// the salt right after the header
+pub const DET_SALT_VERSION: u8 = 0;
+
+/// The fixed salt of deterministic signatures of degree 2^logn:
+/// salt version || logn || "FALCON_DET" zero-padded to 38 bytes
+fn det_salt(logn: usize) -> [u8; SALT_LEN] {
+    let mut salt = [0u8; SALT_LEN];
+    salt[0] = DET_SALT_VERSION;
+    salt[1] = logn as u8;
+    salt[2..12].copy_from_slice(b"FALCON_DET");
+    salt
+}
+
+/// Length of the salt field of a signature: deterministic signatures only
+/// carry the salt version
+fn salt_field_len(deterministic: bool) -> usize {
+    if deterministic { 1 } else { SALT_LEN }
+}
+
+/// Length in bytes of an encoded public key of degree n
+pub fn pk_bytelen(n: usize) -> usize {
+    HEAD_LEN + (14 * n).div_ceil(8)
+}
+
+/// Bit width of the coefficients of f and g in an encoded secret key
+pub(crate) fn fg_bits(n: usize) -> u32 {
+    match n {
+        2..=32 => 8,
+        64 | 128 => 7,
+        256 | 512 => 6,
+        _ => 5,
+    }
+}
+
+/// Length in bytes of an encoded secret key of degree n (F is packed at 8 bits)
+pub fn sk_bytelen(n: usize) -> usize {
+    HEAD_LEN + 2 * (fg_bits(n) as usize * n).div_ceil(8) + n
+}
+
+/// Bit width of the coefficients of s2 in a CT signature
+fn ct_bits(n: usize) -> u32 {
+    match n {
+        2 => 10,
+        4 | 8 => 11,
+        _ => 12,
+    }
+}
+
+/// Length in bytes of a CT signature of degree n
+pub fn ct_sig_bytelen(n: usize) -> usize {
+    HEAD_LEN + SALT_LEN + (ct_bits(n) as usize * n).div_ceil(8)
+}
 
 pub fn logn(n: usize) -> Option<usize> {
     match n {
@@ -49,11 +121,619 @@ pub fn get_params() -> HashMap<usize, FalconParams> {
 #[derive(Clone, Debug)]
 pub struct PublicKey {
     pub n: usize,
-    // Diğer alanlar gerektiğinde eklenir
+    pub params: FalconParams,
+    /// h = g / f mod q
+    pub h: Vec<u32>,
+}
+
+/// Reasons for which a signature is rejected
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The header byte does not match a signature format or the degree
+    InvalidHeader,
+    /// The signature is shorter than header || salt or longer than `sig_bytelen`
+    InvalidLength,
+    /// The compressed s2 cannot be decoded
+    InvalidEncoding,
+    /// The squared norm of (s1, s2) exceeds `sig_bound`
+    NormTooLarge,
+    /// The FN-DSA context string is longer than 255 bytes
+    InvalidContext,
+    /// The HashFN-DSA digest does not have the length of its hash function
+    InvalidDigest,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            VerifyError::InvalidHeader => "invalid signature header",
+            VerifyError::InvalidLength => "invalid signature length",
+            VerifyError::InvalidEncoding => "invalid signature encoding",
+            VerifyError::NormTooLarge => "signature norm too large",
+            VerifyError::InvalidContext => "context string longer than 255 bytes",
+            VerifyError::InvalidDigest => "digest length does not match the hash function",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// Wire formats of a signature
+///
+/// The compressed and padded formats both use the header byte 0x30 + logn and
+/// only differ in how the compressed s2 is terminated. Deterministic
+/// signatures also set `DET_HEADER_BIT` in the header and replace the salt by
+/// the `DET_SALT_VERSION` byte; they have no padded format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignatureFormat {
+    /// Variable length: the compressed s2 stops at its last byte
+    Compressed,
+    /// Fixed length: the compressed s2 is zero-padded to `sig_bytelen`
+    #[default]
+    Padded,
+    /// Fixed length, header 0x50 + logn: s2 is stored with a fixed number of
+    /// bits per coefficient so that decoding does not depend on its values
+    Ct,
+}
+
+/// A Falcon signature: header || salt || encoded s2, or
+/// header || salt version || encoded s2 for a deterministic signature
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    logn: usize,
+    salt: [u8; SALT_LEN],
+    s2: Vec<i32>,
+    deterministic: bool,
+}
+
+impl Signature {
+    /// Base-2 logarithm of the degree
+    pub fn logn(&self) -> usize {
+        self.logn
+    }
+
+    /// The salt hashed along with the message, which is fixed for
+    /// deterministic signatures
+    pub fn salt(&self) -> &[u8; SALT_LEN] {
+        &self.salt
+    }
+
+    /// The decompressed second half of the short vector
+    pub fn s2(&self) -> &[i32] {
+        &self.s2
+    }
+
+    /// Whether the signature was made by `SecretKey::sign_deterministic`
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Encodes the signature in the padded format, or in the compressed
+    /// format for a deterministic signature
+    ///
+    /// Fails if s2 does not compress within `sig_bytelen`, which cannot happen
+    /// for a signature output by the signer.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, &'static str> {
+        if self.deterministic {
+            self.encode(SignatureFormat::Compressed)
+        } else {
+            self.encode(SignatureFormat::Padded)
+        }
+    }
+
+    /// Encodes the signature in the given format
+    ///
+    /// Fails if the format cannot hold s2: a decoded CT signature may not
+    /// compress within `sig_bytelen`, and a compressed one may not fit in the
+    /// CT width. Deterministic signatures cannot be padded.
+    pub fn encode(&self, format: SignatureFormat) -> Result<Vec<u8>, &'static str> {
+        let params = &get_params()[&(1 << self.logn)];
+        if self.deterministic && format == SignatureFormat::Padded {
+            return Err("deterministic signatures have no padded format");
+        }
+        let (header, enc_s) = if format == SignatureFormat::Ct {
+            let enc_s = trim_i16_encode(&self.s2, ct_bits(params.n));
+            (0x50, enc_s.ok_or("s2 does not fit in the CT format")?)
+        } else {
+            let slen = params.sig_bytelen - HEAD_LEN - SALT_LEN;
+            let mut enc_s = compress(&self.s2, slen).ok_or("s2 does not compress within sig_bytelen")?;
+            if format == SignatureFormat::Compressed {
+                // The last bit written is the unary stop bit of the last coefficient
+                while enc_s.last() == Some(&0) {
+                    enc_s.pop();
+                }
+            }
+            (0x30, enc_s)
+        };
+        let mut bytes = Vec::with_capacity(HEAD_LEN + SALT_LEN + enc_s.len());
+        if self.deterministic {
+            bytes.push(DET_HEADER_BIT | header | self.logn as u8);
+            bytes.push(DET_SALT_VERSION);
+        } else {
+            bytes.push(header | self.logn as u8);
+            bytes.extend_from_slice(&self.salt);
+        }
+        bytes.extend(enc_s);
+        Ok(bytes)
+    }
+
+    /// Decodes a signature in the given format, rejecting the other one
+    pub fn decode(bytes: &[u8], format: SignatureFormat) -> Result<Self, VerifyError> {
+        let sig = Self::from_bytes(bytes)?;
+        let header = if format == SignatureFormat::Ct { 0x50 } else { 0x30 };
+        if bytes[0] & 0x70 != header {
+            return Err(VerifyError::InvalidHeader);
+        }
+        let expected_len = match format {
+            SignatureFormat::Padded if sig.deterministic => return Err(VerifyError::InvalidHeader),
+            SignatureFormat::Padded => get_params()[&(1 << sig.logn)].sig_bytelen,
+            SignatureFormat::Compressed => sig.encode(format).map_err(|_| VerifyError::InvalidEncoding)?.len(),
+            SignatureFormat::Ct => ct_sig_bytelen(1 << sig.logn) - SALT_LEN + salt_field_len(sig.deterministic),
+        };
+        if bytes.len() != expected_len {
+            return Err(VerifyError::InvalidLength);
+        }
+        Ok(sig)
+    }
+
+    /// Decodes a signature in any format, the format and the degree being
+    /// given by the header
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VerifyError> {
+        let deterministic = bytes.first().is_some_and(|&b| b & DET_HEADER_BIT != 0);
+        let salt_len = salt_field_len(deterministic);
+        if bytes.len() < HEAD_LEN + salt_len {
+            return Err(VerifyError::InvalidLength);
+        }
+        let ct = match bytes[0] & 0x70 {
+            0x30 => false,
+            0x50 => true,
+            _ => return Err(VerifyError::InvalidHeader),
+        };
+        let logn = (bytes[0] & 0x0F) as usize;
+        let params = get_params().remove(&(1 << logn)).ok_or(VerifyError::InvalidHeader)?;
+        let salt = if deterministic {
+            // The salt version is part of the header: other versions are not supported
+            if bytes[HEAD_LEN] != DET_SALT_VERSION {
+                return Err(VerifyError::InvalidHeader);
+            }
+            det_salt(logn)
+        } else {
+            bytes[HEAD_LEN..HEAD_LEN + SALT_LEN].try_into().expect("length checked above")
+        };
+        let enc_s = &bytes[HEAD_LEN + salt_len..];
+        let s2 = if ct {
+            if bytes.len() != ct_sig_bytelen(params.n) - SALT_LEN + salt_len {
+                return Err(VerifyError::InvalidLength);
+            }
+            trim_i16_decode(enc_s, ct_bits(params.n), params.n).ok_or(VerifyError::InvalidEncoding)?
+        } else {
+            if bytes.len() > params.sig_bytelen - SALT_LEN + salt_len {
+                return Err(VerifyError::InvalidLength);
+            }
+            let slen = params.sig_bytelen - HEAD_LEN - SALT_LEN;
+            decompress(enc_s, slen, params.n).ok_or(VerifyError::InvalidEncoding)?
+        };
+        Ok(Self { logn, salt, s2, deterministic })
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct SecretKey {
     pub n: usize,
-    // Diğer alanlar gerektiğinde eklenir
-} 
\ No newline at end of file
+    pub params: FalconParams,
+    pub f: Vec<i64>,
+    pub g: Vec<i64>,
+    pub big_f: Vec<i64>,
+    pub big_g: Vec<i64>,
+    /// The basis B0 = [[g, -f], [G, -F]] in FFT representation
+    pub b0_fft: [[Vec<Cplx>; 2]; 2],
+    /// The normalized ffLDL tree of B0
+    pub t_fft: LdlTree,
+    /// The public key h = g / f mod q
+    pub h: Vec<u32>,
+}
+
+/// How the signer computes the preimage of the hashed message
+///
+/// Only compiled for the tests and with the `debug-ffnp` feature, since
+/// nearest-plane signatures leak the secret basis.
+#[cfg(any(test, feature = "debug-ffnp"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SamplingMode {
+    /// Gaussian sampling over the ffLDL tree (ffSampling), as the scheme requires
+    #[default]
+    FfSampling,
+    /// Deterministic rounding over the ffLDL tree (ffNP)
+    ///
+    /// For diagnosing tree bugs only: nearest-plane signatures leak the secret basis.
+    NearestPlane,
+}
+
+/// Reduces a polynomial with integer coefficients modulo q
+fn to_zq(f: &[i64]) -> Vec<u32> {
+    f.iter().map(|&x| x.rem_euclid(Q as i64) as u32).collect()
+}
+
+/// Generates the secret key of degree n derived from `seed`, see `SecretKey::from_seed`
+pub fn keygen_from_seed(seed: &[u8], n: usize) -> SecretKey {
+    SecretKey::from_seed(seed, n)
+}
+
+/// Hashes a salted message to a point of Z_q[x] / (x^n + 1)
+///
+/// SHAKE256 is fed with the salt then the message, and its output is read
+/// 16 bits at a time (big-endian), rejecting the values above 5 * q.
+pub fn hash_to_point(message: &[u8], salt: &[u8], n: usize) -> Vec<u32> {
+    let mut shake = Shake256::new();
+    shake.update(salt);
+    shake.update(message);
+    hash_to_point_shake(shake, n)
+}
+
+/// Hashes to a point from a SHAKE256 context that has absorbed salt || message
+pub fn hash_to_point_shake(mut shake: Shake256, n: usize) -> Vec<u32> {
+    let k = (1u32 << 16) / Q as u32;
+    let mut hashed = Vec::with_capacity(n);
+    while hashed.len() < n {
+        let mut twobytes = [0u8; 2];
+        shake.squeeze(&mut twobytes);
+        let elt = ((twobytes[0] as u32) << 8) + twobytes[1] as u32;
+        if elt < k * Q as u32 {
+            hashed.push(elt % Q as u32);
+        }
+    }
+    hashed
+}
+
+impl SecretKey {
+    /// Builds a secret key from an NTRU basis (f, g, F, G) satisfying fG - gF = q
+    ///
+    /// Fails if the basis is not short enough for the sampler, that is if its
+    /// Gram-Schmidt norm exceeds 1.17 * sqrt(q) or a normalized leaf of its
+    /// ffLDL tree falls outside [sigmin, MAX_SIGMA].
+    pub fn from_polys(
+        n: usize,
+        f: Vec<i64>,
+        g: Vec<i64>,
+        big_f: Vec<i64>,
+        big_g: Vec<i64>,
+    ) -> Result<Self, &'static str> {
+        let params = get_params().remove(&n).ok_or("unsupported degree")?;
+        if [&f, &g, &big_f, &big_g].iter().any(|p| p.len() != n) {
+            return Err("polynomials do not match the degree");
+        }
+        let h = div_zq(&to_zq(&g), &to_zq(&f))?;
+        // The sampler assumes a Gram-Schmidt norm of at most 1.17 * sqrt(q)
+        if gs_norm(&f, &g) > 1.17 * 1.17 * Q as f64 {
+            return Err("Gram-Schmidt norm of the basis too large");
+        }
+
+        let as_real = |p: &[i64], sign: i64| p.iter().map(|&x| Real::of_i64(sign * x)).collect::<Vec<Real>>();
+        let b0 = vec![
+            vec![as_real(&g, 1), as_real(&f, -1)],
+            vec![as_real(&big_g, 1), as_real(&big_f, -1)],
+        ];
+        let g0 = gram(&b0);
+        let b0_fft = [
+            [fft(&b0[0][0]), fft(&b0[0][1])],
+            [fft(&b0[1][0]), fft(&b0[1][1])],
+        ];
+        let g0_fft: MatrixFft = g0
+            .iter()
+            .map(|row| row.iter().map(|elt| fft(elt)).collect())
+            .collect();
+        let mut t_fft = ffldl_fft(&g0_fft);
+        normalize_tree(&mut t_fft, Real::of_f64(params.sigma));
+        // The leaves are the standard deviations given to SamplerZ, which
+        // only supports [sigmin, MAX_SIGMA]
+        let (sigmin, max_sigma) = (Real::of_f64(params.sigmin), Real::of_f64(MAX_SIGMA));
+        if t_fft.leaves().iter().any(|&leaf| leaf < sigmin || leaf > max_sigma) {
+            return Err("basis out of the range of the sampler");
+        }
+
+        Ok(Self { n, params, f, g, big_f, big_g, b0_fft, t_fft, h })
+    }
+
+    /// Encodes the secret key as a header byte 0x50 + logn followed by f, g
+    /// and F packed as two's complement values
+    ///
+    /// G is left out since it can be recomputed from f, g and F. Fails if a
+    /// coefficient does not fit in its bit width.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, &'static str> {
+        let bits = fg_bits(self.n);
+        let mut bytes = vec![0x50 + logn(self.n).ok_or("unsupported degree")? as u8];
+        bytes.extend(trim_i8_encode(&self.f, bits).ok_or("f does not fit in the encoding")?);
+        bytes.extend(trim_i8_encode(&self.g, bits).ok_or("g does not fit in the encoding")?);
+        bytes.extend(trim_i8_encode(&self.big_f, 8).ok_or("F does not fit in the encoding")?);
+        Ok(bytes)
+    }
+
+    /// Decodes a secret key encoded by `to_bytes`, recomputing G = gF / f mod q
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let header = *bytes.first().ok_or("empty secret key")?;
+        if header & 0xF0 != 0x50 {
+            return Err("invalid secret key header");
+        }
+        let n = 1usize << (header & 0x0F);
+        if logn(n).is_none() {
+            return Err("unsupported degree");
+        }
+        if bytes.len() != sk_bytelen(n) {
+            return Err("invalid secret key length");
+        }
+        let bits = fg_bits(n);
+        let fg_len = (bits as usize * n).div_ceil(8);
+        let (f_bytes, rest) = bytes[HEAD_LEN..].split_at(fg_len);
+        let (g_bytes, big_f_bytes) = rest.split_at(fg_len);
+        let f = trim_i8_decode(f_bytes, bits, n).ok_or("invalid encoding of f")?;
+        let g = trim_i8_decode(g_bytes, bits, n).ok_or("invalid encoding of g")?;
+        let big_f = trim_i8_decode(big_f_bytes, 8, n).ok_or("invalid encoding of F")?;
+        // fG - gF = q gives G = gF / f mod q
+        let big_g_zq = div_zq(&mul_zq(&to_zq(&g), &to_zq(&big_f)), &to_zq(&f))?;
+        let big_g: Vec<i64> = big_g_zq
+            .iter()
+            .map(|&x| if x > (Q / 2) as u32 { x as i64 - Q as i64 } else { x as i64 })
+            .collect();
+        if big_g.iter().any(|x| x.abs() > 127) {
+            return Err("G does not fit in the encoding");
+        }
+        Self::from_polys(n, f, g, big_f, big_g)
+    }
+
+    /// Generates a fresh secret key of degree n
+    pub fn generate<R: RngCore>(n: usize, rng: &mut R) -> Self {
+        let (f, g, big_f, big_g) = ntru_gen(n, rng);
+        Self::from_polys(n, f, g, big_f, big_g).expect("ntru_gen returns an invertible f")
+    }
+
+    /// Regenerates the secret key of degree n derived from `seed`
+    ///
+    /// All the randomness of the key generation is read from SHAKE256(seed),
+    /// so the same seed gives the same key on every platform.
+    pub fn from_seed(seed: &[u8], n: usize) -> Self {
+        let mut shake = Shake256::new();
+        shake.update(seed);
+        shake.finalize();
+        Self::generate(n, &mut shake)
+    }
+
+    /// Computes the target t = (c, 0) * B0^-1 in FFT representation
+    fn target_fft(&self, point: &[u32]) -> [Vec<Cplx>; 2] {
+        let [[_, b], [_, d]] = &self.b0_fft;
+        let q = Real::of_i64(Q as i64);
+        let point_r: Vec<Real> = point.iter().map(|&x| Real::of_i64(x as i64)).collect();
+        let point_fft = fft(&point_r);
+        let t0_fft = point_fft.iter().zip(d.iter()).map(|(&p, &d)| p * d / q).collect();
+        let t1_fft = point_fft.iter().zip(b.iter()).map(|(&p, &b)| -p * b / q).collect();
+        [t0_fft, t1_fft]
+    }
+
+    /// Maps a lattice vector z (in the B0 basis) to s = (c, 0) - z * B0
+    fn preimage_from(&self, point: &[u32], z: &[Vec<Cplx>; 2]) -> [Vec<i32>; 2] {
+        let [[a, b], [c, d]] = &self.b0_fft;
+        let v0_fft = add_fft(&mul_fft(&z[0], a), &mul_fft(&z[1], c));
+        let v1_fft = add_fft(&mul_fft(&z[0], b), &mul_fft(&z[1], d));
+        let v0 = ifft(&v0_fft);
+        let v1 = ifft(&v1_fft);
+        let s0 = point.iter().zip(v0.iter()).map(|(&p, v)| p as i32 - v.rint() as i32).collect();
+        let s1 = v1.iter().map(|v| -(v.rint() as i32)).collect();
+        [s0, s1]
+    }
+
+    /// Samples a short vector s such that s[0] + s[1] * h = point mod q
+    pub fn sample_preimage<R: RngCore>(&self, point: &[u32], rng: &mut R) -> [Vec<i32>; 2] {
+        let t = self.target_fft(point);
+        let z = ffsampling_fft(&t, &self.t_fft, Real::of_f64(self.params.sigmin), rng);
+        self.preimage_from(point, &z)
+    }
+
+    /// Computes the short vector s such that s[0] + s[1] * h = point mod q
+    /// given by Babai's nearest plane algorithm (ffNP)
+    pub fn nearest_plane_preimage(&self, point: &[u32]) -> [Vec<i32>; 2] {
+        let t = self.target_fft(point);
+        let z = ffnp_fft(&t, &self.t_fft);
+        self.preimage_from(point, &z)
+    }
+
+    /// Signs a message
+    ///
+    /// The signature is header || salt || compressed s2, where the header byte is
+    /// 0x30 + logn and the compressed body is padded to `sig_bytelen`.
+    pub fn sign<R: RngCore>(&self, message: &[u8], rng: &mut R) -> Vec<u8> {
+        self.sign_signature(message, rng).to_bytes().expect("the signer checks that s2 compresses")
+    }
+
+    /// Signs a message, returning the signature before it is encoded
+    pub fn sign_signature<R: RngCore>(&self, message: &[u8], rng: &mut R) -> Signature {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        self.sign_point(&hash_to_point(message, &salt, self.n), salt, rng)
+    }
+
+    /// Signs a message deterministically: signing the same message twice gives
+    /// the same bytes
+    ///
+    /// This follows Falcon-DET1024: the message is hashed with a fixed salt,
+    /// and the sampler randomness is read from SHAKE256(logn || encoded secret
+    /// key || message). The signature is header || `DET_SALT_VERSION` ||
+    /// compressed s2, with `DET_HEADER_BIT` set in the header, and is checked
+    /// with `PublicKey::verify_deterministic`. Fails if the secret key does not
+    /// fit in its encoding.
+    pub fn sign_deterministic(&self, message: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let logn = logn(self.n).ok_or("unsupported degree")?;
+        let mut shake = Shake256::new();
+        shake.update(&[logn as u8]);
+        shake.update(&self.to_bytes()?);
+        shake.update(message);
+        shake.finalize();
+        let salt = det_salt(logn);
+        let mut sig = self.sign_point(&hash_to_point(message, &salt, self.n), salt, &mut shake);
+        sig.deterministic = true;
+        Ok(sig.to_bytes().expect("the signer checks that s2 compresses"))
+    }
+
+    /// Signs a message, encoding the signature in the given format
+    ///
+    /// As in the reference signer, a signature whose s2 does not fit in the
+    /// CT width is resampled.
+    pub fn sign_with_format<R: RngCore>(&self, message: &[u8], rng: &mut R, format: SignatureFormat) -> Vec<u8> {
+        loop {
+            if let Ok(bytes) = self.sign_signature(message, rng).encode(format) {
+                return bytes;
+            }
+        }
+    }
+
+    /// Signs a message, computing the preimage as selected by `mode`
+    #[cfg(any(test, feature = "debug-ffnp"))]
+    pub fn sign_with_mode<R: RngCore>(&self, message: &[u8], rng: &mut R, mode: SamplingMode) -> Vec<u8> {
+        if mode == SamplingMode::FfSampling {
+            return self.sign(message, rng);
+        }
+        // Nearest plane is deterministic: only a new salt can change the outcome
+        let mut salt = [0u8; SALT_LEN];
+        loop {
+            rng.fill_bytes(&mut salt);
+            let s = self.nearest_plane_preimage(&hash_to_point(message, &salt, self.n));
+            if let Some(sig) = self.signature_of(s, salt) {
+                return sig.to_bytes().expect("signature_of checks that s2 compresses");
+            }
+        }
+    }
+
+    /// Signs the point hashed from salt || message with ffSampling
+    pub(crate) fn sign_point<R: RngCore>(&self, point: &[u32], salt: [u8; SALT_LEN], rng: &mut R) -> Signature {
+        loop {
+            let s = self.sample_preimage(point, rng);
+            if let Some(sig) = self.signature_of(s, salt) {
+                return sig;
+            }
+        }
+    }
+
+    /// Builds the signature of s if s is short enough and s2 compresses
+    /// within `sig_bytelen`; otherwise the caller has to resample
+    fn signature_of(&self, s: [Vec<i32>; 2], salt: [u8; SALT_LEN]) -> Option<Signature> {
+        let slen = self.params.sig_bytelen - HEAD_LEN - SALT_LEN;
+        if sqnorm(&s) > self.params.sig_bound as i64 || compress(&s[1], slen).is_none() {
+            return None;
+        }
+        let [_, s2] = s;
+        Some(Signature {
+            logn: logn(self.n).expect("unsupported degree"),
+            salt,
+            s2,
+            deterministic: false,
+        })
+    }
+}
+
+impl PublicKey {
+    /// Derives the public key of a secret key
+    pub fn new(sk: &SecretKey) -> Self {
+        Self {
+            n: sk.n,
+            params: sk.params.clone(),
+            h: sk.h.clone(),
+        }
+    }
+
+    /// Encodes the public key as a header byte 0x00 + logn followed by the
+    /// coefficients of h packed at 14 bits each
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = BitWriter::new();
+        for &x in &self.h {
+            w.write(x, 14);
+        }
+        let mut bytes = vec![logn(self.n).expect("unsupported degree") as u8];
+        bytes.extend(w.into_bytes());
+        bytes
+    }
+
+    /// Decodes a public key encoded by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let header = *bytes.first().ok_or("empty public key")?;
+        if header & 0xF0 != 0x00 {
+            return Err("invalid public key header");
+        }
+        let n = 1usize << (header & 0x0F);
+        let params = get_params().remove(&n).ok_or("unsupported degree")?;
+        if bytes.len() != pk_bytelen(n) {
+            return Err("invalid public key length");
+        }
+        let mut r = BitReader::new(&bytes[HEAD_LEN..]);
+        let mut h = Vec::with_capacity(n);
+        for _ in 0..n {
+            let x = r.read(14).ok_or("invalid public key length")?;
+            if x >= Q as u32 {
+                return Err("public key coefficient out of range");
+            }
+            h.push(x);
+        }
+        if !r.rest_is_zero() {
+            return Err("non-zero padding in public key");
+        }
+        Ok(Self { n, params, h })
+    }
+
+    /// Verifies a header || salt || compressed s2 signature of a message
+    ///
+    /// Deterministic signatures are rejected, see `verify_deterministic`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), VerifyError> {
+        self.verify_hashed(message, &self.decode_signature(signature, false)?)
+    }
+
+    /// Verifies a deterministic signature of a message, as output by
+    /// `SecretKey::sign_deterministic`
+    ///
+    /// The header must have `DET_HEADER_BIT` set.
+    pub fn verify_deterministic(&self, message: &[u8], signature: &[u8]) -> Result<(), VerifyError> {
+        self.verify_hashed(message, &self.decode_signature(signature, true)?)
+    }
+
+    /// Verifies a decoded signature of a message
+    ///
+    /// Deterministic signatures are rejected, see `verify_deterministic`.
+    pub fn verify_signature(&self, message: &[u8], signature: &Signature) -> Result<(), VerifyError> {
+        if signature.deterministic {
+            return Err(VerifyError::InvalidHeader);
+        }
+        self.verify_hashed(message, signature)
+    }
+
+    /// Decodes a signature, rejecting a signature for another degree, or
+    /// whose `DET_HEADER_BIT` differs from `deterministic`, before decoding it
+    pub(crate) fn decode_signature(&self, signature: &[u8], deterministic: bool) -> Result<Signature, VerifyError> {
+        let logn = logn(self.n).expect("unsupported degree") as u8;
+        let det_bit = if deterministic { DET_HEADER_BIT } else { 0 };
+        if signature.first().is_some_and(|&b| b & 0x0F != logn || b & DET_HEADER_BIT != det_bit) {
+            return Err(VerifyError::InvalidHeader);
+        }
+        Signature::from_bytes(signature)
+    }
+
+    /// Checks a signature of the point hashed from its salt and the message
+    fn verify_hashed(&self, message: &[u8], signature: &Signature) -> Result<(), VerifyError> {
+        if Some(signature.logn) != logn(self.n) {
+            return Err(VerifyError::InvalidHeader);
+        }
+        self.verify_point(&hash_to_point(message, &signature.salt, self.n), signature)
+    }
+
+    /// Checks a signature of the point hashed from salt || message
+    pub(crate) fn verify_point(&self, hashed: &[u32], signature: &Signature) -> Result<(), VerifyError> {
+        let s2 = signature.s2.clone();
+
+        // s1 = c - s2 * h mod q, with coefficients centered around 0
+        let s2_zq: Vec<u32> = s2.iter().map(|&x| x.rem_euclid(Q) as u32).collect();
+        let s1: Vec<i32> = sub_zq(hashed, &mul_zq(&s2_zq, &self.h))
+            .iter()
+            .map(|&x| ((x as i32 + (Q >> 1)) % Q) - (Q >> 1))
+            .collect();
+        if sqnorm(&[s1, s2]) > self.params.sig_bound as i64 {
+            return Err(VerifyError::NormTooLarge);
+        }
+        Ok(())
+    }
+}
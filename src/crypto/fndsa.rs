@@ -0,0 +1,279 @@
+// FN-DSA (FIPS 206) kipi: parametre kümeleri, bağlam dizesi ve ön özetleme
+
+use rand::RngCore;
+use crate::crypto::falcon::{get_params, hash_to_point_shake, FalconParams, PublicKey, SecretKey, VerifyError, SALT_LEN};
+use crate::utils::sha2::{Sha256, Sha512};
+use crate::utils::shake::{shake256, Sha3, Shake128, Shake256};
+
+/// Maximum length of an FN-DSA context string
+pub const MAX_CONTEXT_LEN: usize = 255;
+/// Length of the public key hash absorbed by the FN-DSA hash-to-point
+pub const HPK_LEN: usize = 64;
+
+/// The parameter sets standardised as FN-DSA
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterSet {
+    FnDsa512,
+    FnDsa1024,
+}
+
+impl ParameterSet {
+    pub fn n(self) -> usize {
+        match self {
+            ParameterSet::FnDsa512 => 512,
+            ParameterSet::FnDsa1024 => 1024,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ParameterSet::FnDsa512 => "FN-DSA-512",
+            ParameterSet::FnDsa1024 => "FN-DSA-1024",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "FN-DSA-512" => Some(ParameterSet::FnDsa512),
+            "FN-DSA-1024" => Some(ParameterSet::FnDsa1024),
+            _ => None,
+        }
+    }
+
+    pub fn params(self) -> FalconParams {
+        get_params()[&self.n()].clone()
+    }
+}
+
+/// Hash functions allowed for HashFN-DSA, identified in the signed message
+/// by their DER-encoded OID
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreHash {
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    /// SHAKE128 with a 256-bit output
+    Shake128,
+    /// SHAKE256 with a 512-bit output
+    Shake256,
+}
+
+impl PreHash {
+    /// DER encoding of the OID, under 2.16.840.1.101.3.4.2
+    pub fn oid(self) -> [u8; 11] {
+        let last = match self {
+            PreHash::Sha256 => 0x01,
+            PreHash::Sha384 => 0x02,
+            PreHash::Sha512 => 0x03,
+            PreHash::Sha3_256 => 0x08,
+            PreHash::Sha3_384 => 0x09,
+            PreHash::Sha3_512 => 0x0A,
+            PreHash::Shake128 => 0x0B,
+            PreHash::Shake256 => 0x0C,
+        };
+        [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, last]
+    }
+
+    /// Length in bytes of the digest
+    pub fn digest_len(self) -> usize {
+        match self {
+            PreHash::Sha256 | PreHash::Sha3_256 | PreHash::Shake128 => 32,
+            PreHash::Sha384 | PreHash::Sha3_384 => 48,
+            PreHash::Sha512 | PreHash::Sha3_512 | PreHash::Shake256 => 64,
+        }
+    }
+
+    /// Starts an incremental hash of a message
+    pub fn hasher(self) -> PreHasher {
+        let state = match self {
+            PreHash::Sha256 => HashState::Sha256(Sha256::new()),
+            PreHash::Sha384 => HashState::Sha512(Sha512::new_384()),
+            PreHash::Sha512 => HashState::Sha512(Sha512::new()),
+            PreHash::Sha3_256 | PreHash::Sha3_384 | PreHash::Sha3_512 => HashState::Sha3(Sha3::new(self.digest_len())),
+            PreHash::Shake128 => HashState::Shake128(Shake128::new()),
+            PreHash::Shake256 => HashState::Shake256(Shake256::new()),
+        };
+        PreHasher { ph: self, state }
+    }
+
+    /// Hashes a whole message
+    pub fn digest(self, message: &[u8]) -> Vec<u8> {
+        let mut hasher = self.hasher();
+        hasher.update(message);
+        hasher.finalize()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum HashState {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Sha3(Sha3),
+    Shake128(Shake128),
+    Shake256(Shake256),
+}
+
+/// Incremental hash of a message for HashFN-DSA, so that large messages can
+/// be signed without being held in memory
+#[derive(Clone, Debug)]
+pub struct PreHasher {
+    ph: PreHash,
+    state: HashState,
+}
+
+impl PreHasher {
+    /// The hash function in use
+    pub fn pre_hash(&self) -> PreHash {
+        self.ph
+    }
+
+    /// Absorbs a chunk of the message
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HashState::Sha256(ctx) => ctx.update(data),
+            HashState::Sha512(ctx) => ctx.update(data),
+            HashState::Sha3(ctx) => ctx.update(data),
+            HashState::Shake128(ctx) => ctx.update(data),
+            HashState::Shake256(ctx) => ctx.update(data),
+        }
+    }
+
+    /// Returns the digest of the message
+    pub fn finalize(self) -> Vec<u8> {
+        let mut out = vec![0u8; self.ph.digest_len()];
+        match self.state {
+            HashState::Sha256(ctx) => ctx.finalize(),
+            HashState::Sha512(ctx) => ctx.finalize(),
+            HashState::Sha3(ctx) => ctx.finalize(),
+            HashState::Shake128(mut ctx) => {
+                ctx.squeeze(&mut out);
+                out
+            }
+            HashState::Shake256(mut ctx) => {
+                ctx.squeeze(&mut out);
+                out
+            }
+        }
+    }
+}
+
+/// Encodes a message for FN-DSA: 0x00 || len(ctx) || ctx || message
+pub fn encode_message(message: &[u8], ctx: &[u8]) -> Result<Vec<u8>, &'static str> {
+    if ctx.len() > MAX_CONTEXT_LEN {
+        return Err("context string longer than 255 bytes");
+    }
+    let mut m = Vec::with_capacity(2 + ctx.len() + message.len());
+    m.push(0x00);
+    m.push(ctx.len() as u8);
+    m.extend_from_slice(ctx);
+    m.extend_from_slice(message);
+    Ok(m)
+}
+
+/// Encodes a digest for HashFN-DSA: 0x01 || len(ctx) || ctx || OID || digest
+pub fn encode_prehash(ph: PreHash, digest: &[u8], ctx: &[u8]) -> Result<Vec<u8>, &'static str> {
+    if ctx.len() > MAX_CONTEXT_LEN {
+        return Err("context string longer than 255 bytes");
+    }
+    if digest.len() != ph.digest_len() {
+        return Err("digest length does not match the hash function");
+    }
+    let mut m = Vec::with_capacity(2 + ctx.len() + 11 + digest.len());
+    m.push(0x01);
+    m.push(ctx.len() as u8);
+    m.extend_from_slice(ctx);
+    m.extend_from_slice(&ph.oid());
+    m.extend_from_slice(digest);
+    Ok(m)
+}
+
+/// Hashes the encoded public key to the `HPK_LEN` bytes FN-DSA binds into
+/// hash-to-point
+pub fn public_key_hash(pk: &PublicKey) -> Vec<u8> {
+    shake256(&pk.to_bytes(), HPK_LEN)
+}
+
+/// Hashes an encoded FN-DSA message to a point: SHAKE256 is fed with the salt,
+/// the public key hash and then the message
+///
+/// The public key hash separates FN-DSA signatures from round-3 ones, whose
+/// hash-to-point starts the message right after the salt.
+pub fn fn_dsa_hash_to_point(m: &[u8], salt: &[u8], hpk: &[u8], n: usize) -> Vec<u32> {
+    let mut shake = Shake256::new();
+    shake.update(salt);
+    shake.update(hpk);
+    shake.update(m);
+    hash_to_point_shake(shake, n)
+}
+
+impl SecretKey {
+    /// Signs an encoded FN-DSA message, in the padded format
+    fn sign_encoded<R: RngCore>(&self, m: &[u8], rng: &mut R) -> Vec<u8> {
+        let hpk = public_key_hash(&PublicKey::new(self));
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let point = fn_dsa_hash_to_point(m, &salt, &hpk, self.n);
+        self.sign_point(&point, salt, rng).to_bytes().expect("the signer checks that s2 compresses")
+    }
+
+    /// Signs a message in FN-DSA mode, under the context string `ctx`
+    pub fn sign_fn_dsa<R: RngCore>(&self, message: &[u8], ctx: &[u8], rng: &mut R) -> Result<Vec<u8>, &'static str> {
+        Ok(self.sign_encoded(&encode_message(message, ctx)?, rng))
+    }
+
+    /// Signs the digest of a message in HashFN-DSA mode
+    pub fn sign_hash_fn_dsa<R: RngCore>(
+        &self,
+        ph: PreHash,
+        digest: &[u8],
+        ctx: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, &'static str> {
+        Ok(self.sign_encoded(&encode_prehash(ph, digest, ctx)?, rng))
+    }
+
+    /// Signs a message hashed incrementally by `hasher`, in HashFN-DSA mode
+    pub fn sign_prehashed<R: RngCore>(&self, hasher: PreHasher, ctx: &[u8], rng: &mut R) -> Result<Vec<u8>, &'static str> {
+        let ph = hasher.pre_hash();
+        self.sign_hash_fn_dsa(ph, &hasher.finalize(), ctx, rng)
+    }
+}
+
+impl PublicKey {
+    /// Verifies a signature of an encoded FN-DSA message
+    fn verify_encoded(&self, m: &[u8], signature: &[u8]) -> Result<(), VerifyError> {
+        let signature = self.decode_signature(signature, false)?;
+        let point = fn_dsa_hash_to_point(m, signature.salt(), &public_key_hash(self), self.n);
+        self.verify_point(&point, &signature)
+    }
+
+    /// Verifies an FN-DSA signature of a message under the context string `ctx`
+    pub fn verify_fn_dsa(&self, message: &[u8], ctx: &[u8], signature: &[u8]) -> Result<(), VerifyError> {
+        let m = encode_message(message, ctx).map_err(|_| VerifyError::InvalidContext)?;
+        self.verify_encoded(&m, signature)
+    }
+
+    /// Verifies a HashFN-DSA signature of a digest
+    pub fn verify_hash_fn_dsa(
+        &self,
+        ph: PreHash,
+        digest: &[u8],
+        ctx: &[u8],
+        signature: &[u8],
+    ) -> Result<(), VerifyError> {
+        if ctx.len() > MAX_CONTEXT_LEN {
+            return Err(VerifyError::InvalidContext);
+        }
+        let m = encode_prehash(ph, digest, ctx).map_err(|_| VerifyError::InvalidDigest)?;
+        self.verify_encoded(&m, signature)
+    }
+
+    /// Verifies a HashFN-DSA signature of a message hashed incrementally by `hasher`
+    pub fn verify_prehashed(&self, hasher: PreHasher, ctx: &[u8], signature: &[u8]) -> Result<(), VerifyError> {
+        let ph = hasher.pre_hash();
+        self.verify_hash_fn_dsa(ph, &hasher.finalize(), ctx, signature)
+    }
+}
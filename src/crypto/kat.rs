@@ -0,0 +1,205 @@
+// NIST PQC bilinen yanıt testleri (.rsp) için ayrıştırıcı ve denetleyici
+
+use rand::RngCore;
+use crate::crypto::falcon::{logn, PublicKey, SecretKey, SignatureFormat, HEAD_LEN, SALT_LEN};
+
+/// One record of a NIST PQC `.rsp` file
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KatEntry {
+    pub count: usize,
+    /// Seed of the DRBG the record was generated with
+    pub seed: Vec<u8>,
+    pub mlen: usize,
+    pub msg: Vec<u8>,
+    pub pk: Vec<u8>,
+    pub sk: Vec<u8>,
+    pub smlen: usize,
+    /// Signed message: sig_len || nonce || msg || header || compressed s2
+    pub sm: Vec<u8>,
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, &'static str> {
+    if !s.len().is_multiple_of(2) {
+        return Err("odd number of hex digits");
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| "invalid hex digit"))
+        .collect()
+}
+
+fn to_hex(b: &[u8]) -> String {
+    b.iter().map(|x| format!("{:02X}", x)).collect()
+}
+
+/// Parses the records of a `.rsp` file, skipping comments and blank lines
+pub fn parse_rsp(text: &str) -> Result<Vec<KatEntry>, &'static str> {
+    let mut entries: Vec<KatEntry> = Vec::new();
+    let mut seen = 0u8;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or("expected `key = value`")?;
+        let (key, value) = (key.trim(), value.trim());
+        if key == "count" {
+            if !entries.is_empty() && seen != 0xFF {
+                return Err("incomplete record");
+            }
+            entries.push(KatEntry::default());
+            seen = 0;
+        }
+        let entry = entries.last_mut().ok_or("record does not start with `count`")?;
+        let decimal = |v: &str| v.parse::<usize>().map_err(|_| "invalid decimal value");
+        let bit = match key {
+            "count" => {
+                entry.count = decimal(value)?;
+                0
+            }
+            "seed" => {
+                entry.seed = from_hex(value)?;
+                1
+            }
+            "mlen" => {
+                entry.mlen = decimal(value)?;
+                2
+            }
+            "msg" => {
+                entry.msg = from_hex(value)?;
+                3
+            }
+            "pk" => {
+                entry.pk = from_hex(value)?;
+                4
+            }
+            "sk" => {
+                entry.sk = from_hex(value)?;
+                5
+            }
+            "smlen" => {
+                entry.smlen = decimal(value)?;
+                6
+            }
+            "sm" => {
+                entry.sm = from_hex(value)?;
+                7
+            }
+            _ => return Err("unknown field"),
+        };
+        if seen & (1 << bit) != 0 {
+            return Err("duplicate field");
+        }
+        seen |= 1 << bit;
+    }
+    if !entries.is_empty() && seen != 0xFF {
+        return Err("incomplete record");
+    }
+    if entries.iter().any(|e| e.msg.len() != e.mlen || e.sm.len() != e.smlen) {
+        return Err("length does not match its field");
+    }
+    Ok(entries)
+}
+
+/// Writes records in the `.rsp` format, under a `# header` line
+pub fn write_rsp(header: &str, entries: &[KatEntry]) -> String {
+    let mut out = format!("# {}\n\n", header);
+    for e in entries {
+        out += &format!("count = {}\n", e.count);
+        out += &format!("seed = {}\n", to_hex(&e.seed));
+        out += &format!("mlen = {}\n", e.mlen);
+        out += &format!("msg = {}\n", to_hex(&e.msg));
+        out += &format!("pk = {}\n", to_hex(&e.pk));
+        out += &format!("sk = {}\n", to_hex(&e.sk));
+        out += &format!("smlen = {}\n", e.smlen);
+        out += &format!("sm = {}\n\n", to_hex(&e.sm));
+    }
+    out
+}
+
+/// The parts of a signed message of the NIST API
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedMessage<'a> {
+    pub nonce: &'a [u8],
+    pub msg: &'a [u8],
+    /// Header 0x20 + logn followed by the compressed s2
+    pub esig: &'a [u8],
+}
+
+/// Splits a signed message sig_len || nonce || msg || esig
+pub fn split_signed_message(sm: &[u8], mlen: usize) -> Result<SignedMessage<'_>, &'static str> {
+    if sm.len() < 2 + SALT_LEN + mlen {
+        return Err("signed message too short");
+    }
+    let sig_len = u16::from_be_bytes([sm[0], sm[1]]) as usize;
+    if sm.len() != 2 + SALT_LEN + mlen + sig_len || sig_len < HEAD_LEN {
+        return Err("signature length does not match the signed message");
+    }
+    let (nonce, rest) = sm[2..].split_at(SALT_LEN);
+    let (msg, esig) = rest.split_at(mlen);
+    Ok(SignedMessage { nonce, msg, esig })
+}
+
+/// Assembles a signed message of the NIST API from a compressed signature
+/// header || nonce || compressed s2
+fn signed_message(msg: &[u8], sig: &[u8]) -> Vec<u8> {
+    let esig_len = sig.len() - SALT_LEN;
+    let mut sm = (esig_len as u16).to_be_bytes().to_vec();
+    sm.extend_from_slice(&sig[HEAD_LEN..HEAD_LEN + SALT_LEN]);
+    sm.extend_from_slice(msg);
+    sm.push(sig[0] - 0x10);
+    sm.extend_from_slice(&sig[HEAD_LEN + SALT_LEN..]);
+    sm
+}
+
+/// Checks a record against this implementation: the keys must decode and
+/// re-encode to the same bytes, pk must be the public key of sk and sm must
+/// carry msg with a valid signature under pk
+pub fn check_kat_entry(entry: &KatEntry) -> Result<(), &'static str> {
+    let sk = SecretKey::from_bytes(&entry.sk)?;
+    let pk = PublicKey::from_bytes(&entry.pk)?;
+    if sk.to_bytes()? != entry.sk || pk.to_bytes() != entry.pk {
+        return Err("keys do not re-encode to the same bytes");
+    }
+    if PublicKey::new(&sk).h != pk.h {
+        return Err("pk is not the public key of sk");
+    }
+    let SignedMessage { nonce, msg, esig } = split_signed_message(&entry.sm, entry.mlen)?;
+    if msg != entry.msg {
+        return Err("signed message does not contain msg");
+    }
+    let logn = logn(pk.n).ok_or("unsupported degree")? as u8;
+    if esig[0] != 0x20 + logn {
+        return Err("invalid signature header");
+    }
+    let mut sig = vec![0x30 + logn];
+    sig.extend_from_slice(nonce);
+    sig.extend_from_slice(&esig[HEAD_LEN..]);
+    pk.verify(msg, &sig).map_err(|_| "signature does not verify")
+}
+
+/// Generates a record the way the NIST generator drives the API: `rng`, a
+/// DRBG seeded with `seed`, first gives the 48-byte key generation seed, then
+/// the signer draws the nonce and the sampler randomness from it
+pub fn generate_kat_entry<R: RngCore>(
+    count: usize,
+    seed: &[u8],
+    msg: &[u8],
+    n: usize,
+    rng: &mut R,
+) -> Result<KatEntry, &'static str> {
+    let mut keygen_seed = [0u8; 48];
+    rng.fill_bytes(&mut keygen_seed);
+    let sk = SecretKey::from_seed(&keygen_seed, n);
+    let sig = sk.sign_with_format(msg, rng, SignatureFormat::Compressed);
+    let sm = signed_message(msg, &sig);
+    Ok(KatEntry {
+        count,
+        seed: seed.to_vec(),
+        mlen: msg.len(),
+        msg: msg.to_vec(),
+        pk: PublicKey::new(&sk).to_bytes(),
+        sk: sk.to_bytes()?,
+        smlen: sm.len(),
+        sm,
+    })
+}
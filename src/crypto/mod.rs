@@ -2,14 +2,23 @@
 //! 
 //! This module contains cryptographic operations including:
 //! - Main Falcon signature scheme
+//! - FN-DSA (FIPS 206) mode with context strings and pre-hashing
+//! - Streaming signer and verifier for messages given in chunks
 //! - NTRU key generation
 //! - Signature encoding/decoding
+//! - NIST known-answer test (.rsp) files
 
 pub mod falcon;
+pub mod fndsa;
+pub mod stream;
 pub mod ntrugen;
 pub mod encoding;
+pub mod kat;
 
 // Re-export commonly used items
 pub use falcon::*;
+pub use fndsa::*;
+pub use stream::*;
 pub use ntrugen::*;
-pub use encoding::*; 
\ No newline at end of file
+pub use encoding::*;
+pub use kat::*; 
\ No newline at end of file
@@ -1,89 +1,236 @@
 // Falcon için NTRU anahtar üretimi ve yardımcı fonksiyonlar (ntrugen.py'den çevrildi)
+use rand::RngCore;
+use crate::crypto::falcon::fg_bits;
+use crate::math::fft::{fft, ifft, add, mul, div, adj, add_fft, mul_fft, div_fft, adj_fft};
+use crate::math::bigint::{BigInt, Coef};
+use crate::math::fpr::{FloatOps, Real};
+use crate::math::ntt::ntt;
+use crate::math::samplerz::samplerz;
+use crate::utils::common::Q;
 
-pub fn karatsuba(a: &[i64], b: &[i64], n: usize) -> Vec<i64> {
+pub fn karatsuba<T: Coef>(a: &[T], b: &[T], n: usize) -> Vec<T> {
     if n == 1 {
-        vec![a[0] * b[0], 0]
+        vec![a[0].clone() * &b[0], T::from(0)]
     } else {
         let n2 = n / 2;
         let a0 = &a[..n2];
         let a1 = &a[n2..];
         let b0 = &b[..n2];
         let b1 = &b[n2..];
-        let ax: Vec<i64> = a0.iter().zip(a1.iter()).map(|(&x, &y)| x + y).collect();
-        let bx: Vec<i64> = b0.iter().zip(b1.iter()).map(|(&x, &y)| x + y).collect();
-        let mut a0b0 = karatsuba(a0, b0, n2);
-        let mut a1b1 = karatsuba(a1, b1, n2);
+        let ax: Vec<T> = a0.iter().zip(a1.iter()).map(|(x, y)| x.clone() + y).collect();
+        let bx: Vec<T> = b0.iter().zip(b1.iter()).map(|(x, y)| x.clone() + y).collect();
+        let a0b0 = karatsuba(a0, b0, n2);
+        let a1b1 = karatsuba(a1, b1, n2);
         let mut axbx = karatsuba(&ax, &bx, n2);
         for i in 0..n {
-            axbx[i] -= a0b0[i] + a1b1[i];
+            axbx[i] -= &a0b0[i];
+            axbx[i] -= &a1b1[i];
         }
-        let mut ab = vec![0; 2 * n];
+        let mut ab = vec![T::from(0); 2 * n];
         for i in 0..n {
-            ab[i] += a0b0[i];
-            ab[i + n] += a1b1[i];
-            ab[i + n2] += axbx[i];
+            ab[i] += &a0b0[i];
+            ab[i + n] += &a1b1[i];
+            ab[i + n2] += &axbx[i];
         }
         ab
     }
 }
 
-pub fn karamul(a: &[i64], b: &[i64]) -> Vec<i64> {
+pub fn karamul<T: Coef>(a: &[T], b: &[T]) -> Vec<T> {
     let n = a.len();
     let ab = karatsuba(a, b, n);
-    (0..n).map(|i| ab[i] - ab[i + n]).collect()
+    (0..n).map(|i| ab[i].clone() - &ab[i + n]).collect()
 }
 
-pub fn galois_conjugate(a: &[i64]) -> Vec<i64> {
-    let n = a.len();
-    (0..n).map(|i| if i % 2 == 0 { a[i] } else { -a[i] }).collect()
+pub fn galois_conjugate<T: Coef>(a: &[T]) -> Vec<T> {
+    a.iter()
+        .enumerate()
+        .map(|(i, x)| if i % 2 == 0 { x.clone() } else { -x.clone() })
+        .collect()
 }
 
-pub fn field_norm(a: &[i64]) -> Vec<i64> {
+pub fn field_norm<T: Coef>(a: &[T]) -> Vec<T> {
     let n2 = a.len() / 2;
-    let ae: Vec<i64> = (0..n2).map(|i| a[2 * i]).collect();
-    let ao: Vec<i64> = (0..n2).map(|i| a[2 * i + 1]).collect();
+    let ae: Vec<T> = (0..n2).map(|i| a[2 * i].clone()).collect();
+    let ao: Vec<T> = (0..n2).map(|i| a[2 * i + 1].clone()).collect();
     let ae_squared = karamul(&ae, &ae);
     let ao_squared = karamul(&ao, &ao);
-    let mut res = ae_squared.clone();
+    let mut res = ae_squared;
     for i in 0..(n2 - 1) {
-        res[i + 1] -= ao_squared[i];
+        res[i + 1] -= &ao_squared[i];
     }
-    res[0] += ao_squared[n2 - 1];
+    res[0] += &ao_squared[n2 - 1];
     res
 }
 
-pub fn lift(a: &[i64]) -> Vec<i64> {
+pub fn lift<T: Coef>(a: &[T]) -> Vec<T> {
     let n = a.len();
-    let mut res = vec![0; 2 * n];
+    let mut res = vec![T::from(0); 2 * n];
     for i in 0..n {
-        res[2 * i] = a[i];
+        res[2 * i] = a[i].clone();
     }
     res
 }
 
-pub fn bitsize(a: i64) -> u32 {
-    let mut val = a.abs();
-    let mut res = 0;
-    while val != 0 {
-        res += 8;
-        val >>= 8;
-    }
-    res
+pub fn bitsize<T: Coef>(a: &T) -> u32 {
+    a.bitsize()
 }
 
-pub fn xgcd(mut b: i64, mut n: i64) -> (i64, i64, i64) {
-    let (mut x0, mut x1, mut y0, mut y1) = (1, 0, 0, 1);
-    while n != 0 {
-        let q = b / n;
-        let t = n;
-        n = b % n;
+pub fn xgcd<T: Coef>(mut b: T, mut n: T) -> (T, T, T) {
+    let zero = T::from(0);
+    let (mut x0, mut x1, mut y0, mut y1) = (T::from(1), T::from(0), T::from(0), T::from(1));
+    while n != zero {
+        let q = b.clone() / &n;
+        let t = n.clone();
+        n = b % &n;
         b = t;
-        let t = x1;
-        x1 = x0 - q * x1;
+        let t = x1.clone();
+        x1 = x0 - &(q.clone() * &x1);
         x0 = t;
-        let t = y1;
-        y1 = y0 - q * y1;
+        let t = y1.clone();
+        y1 = y0 - &(q * &y1);
         y0 = t;
     }
     (b, x0, y0)
-} 
\ No newline at end of file
+}
+
+/// Reduces (F, G) relatively to (f, g) with Babai's round-off algorithm
+///
+/// Only the top 53 bits of each coefficient go through the FFT, so the
+/// reduction is repeated until it no longer shortens (F, G).
+pub fn reduce(f: &[BigInt], g: &[BigInt], big_f: &mut [BigInt], big_g: &mut [BigInt]) {
+    let n = f.len();
+    let max_bitsize = |a: &[BigInt], b: &[BigInt]| a.iter().chain(b.iter()).map(bitsize).max().unwrap_or(0);
+    let size = max_bitsize(f, g).max(53);
+    let adjust = |a: &[BigInt], s: u32| {
+        a.iter()
+            .map(|x| Real::of_i64((x >> (s - 53)).to_i64().expect("adjusted to 53 bits")))
+            .collect::<Vec<Real>>()
+    };
+    let fa_fft = fft(&adjust(f, size));
+    let ga_fft = fft(&adjust(g, size));
+    let den_fft = add_fft(
+        &mul_fft(&fa_fft, &adj_fft(&fa_fft)),
+        &mul_fft(&ga_fft, &adj_fft(&ga_fft)),
+    );
+    loop {
+        let big_size = max_bitsize(big_f, big_g).max(53);
+        if big_size < size {
+            break;
+        }
+        let big_fa_fft = fft(&adjust(big_f, big_size));
+        let big_ga_fft = fft(&adjust(big_g, big_size));
+        let num_fft = add_fft(
+            &mul_fft(&big_fa_fft, &adj_fft(&fa_fft)),
+            &mul_fft(&big_ga_fft, &adj_fft(&ga_fft)),
+        );
+        let k_real = ifft(&div_fft(&num_fft, &den_fft));
+        // The shift by (Size - size) can leave a quotient below 1/2 that rounds
+        // to zero and stalls the reduction, so keep up to 30 more bits of it.
+        let k_max = k_real.iter().map(|x| x.rint().unsigned_abs()).max().unwrap_or(0);
+        let extra = 30u32.saturating_sub(64 - k_max.leading_zeros()).min(big_size - size);
+        let scale = Real::of_i64(1 << extra);
+        let k: Vec<BigInt> = k_real
+            .iter()
+            .map(|&x| BigInt::from((x * scale).rint()))
+            .collect();
+        if k.iter().all(BigInt::is_zero) {
+            break;
+        }
+        let shift = big_size - size - extra;
+        let fk = karamul(f, &k);
+        let gk = karamul(g, &k);
+        for i in 0..n {
+            big_f[i] -= &(&fk[i] << shift);
+            big_g[i] -= &(&gk[i] << shift);
+        }
+    }
+}
+
+/// Solves the NTRU equation fG - gF = q over arbitrary precision integers
+fn ntru_solve_big(f: &[BigInt], g: &[BigInt]) -> Result<(Vec<BigInt>, Vec<BigInt>), &'static str> {
+    let n = f.len();
+    if n == 1 {
+        let (d, u, v) = xgcd(f[0].clone(), g[0].clone());
+        if d != BigInt::from(1) {
+            return Err("f and g are not coprime");
+        }
+        let q = BigInt::from(Q as i64);
+        return Ok((vec![-(&q * &v)], vec![&q * &u]));
+    }
+    let fp = field_norm(f);
+    let gp = field_norm(g);
+    let (big_fp, big_gp) = ntru_solve_big(&fp, &gp)?;
+    let mut big_f = karamul(&lift(&big_fp), &galois_conjugate(g));
+    let mut big_g = karamul(&lift(&big_gp), &galois_conjugate(f));
+    reduce(f, g, &mut big_f, &mut big_g);
+    Ok((big_f, big_g))
+}
+
+/// Solves the NTRU equation fG - gF = q for (F, G)
+///
+/// The intermediate coefficients grow to thousands of bits for the standard
+/// degrees, so the tower is computed with `BigInt`; the reduced solution fits in i64.
+pub fn ntru_solve(f: &[i64], g: &[i64]) -> Result<(Vec<i64>, Vec<i64>), &'static str> {
+    let to_big = |a: &[i64]| a.iter().map(|&x| BigInt::from(x)).collect::<Vec<BigInt>>();
+    let (big_f, big_g) = ntru_solve_big(&to_big(f), &to_big(g))?;
+    let to_small = |a: &[BigInt]| a.iter().map(|x| x.to_i64()).collect::<Option<Vec<i64>>>();
+    match (to_small(&big_f), to_small(&big_g)) {
+        (Some(big_f), Some(big_g)) => Ok((big_f, big_g)),
+        _ => Err("reduced solution does not fit in i64"),
+    }
+}
+
+/// Computes the squared Gram-Schmidt norm of the NTRU basis generated by (f, g)
+pub fn gs_norm(f: &[i64], g: &[i64]) -> f64 {
+    let f: Vec<Real> = f.iter().map(|&x| Real::of_i64(x)).collect();
+    let g: Vec<Real> = g.iter().map(|&x| Real::of_i64(x)).collect();
+    let sqnorm_real = |v: &[&[Real]]| v.iter().flat_map(|p| p.iter()).fold(Real::of_i64(0), |acc, &x| acc + x * x);
+    let sqnorm_fg = sqnorm_real(&[&f, &g]);
+    let ffgg = add(&mul(&f, &adj(&f)), &mul(&g, &adj(&g)));
+    let ft = div(&adj(&g), &ffgg);
+    let gt = div(&adj(&f), &ffgg);
+    let q = Real::of_i64(Q as i64);
+    let sqnorm_big_fg = q * q * sqnorm_real(&[&ft, &gt]);
+    sqnorm_fg.to_f64().max(sqnorm_big_fg.to_f64())
+}
+
+/// Generates a polynomial of degree < n with coefficients following a
+/// discrete Gaussian of standard deviation 1.17 * sqrt(q / (2n))
+pub fn gen_poly<R: RngCore>(n: usize, rng: &mut R) -> Vec<i64> {
+    // 1.17 * sqrt(q / 8192)
+    let sigma = Real::of_f64(1.43300980528773);
+    let (mu, sigmin) = (Real::of_i64(0), Real::of_f64(1.3));
+    assert!(n < 4096);
+    let f0: Vec<i64> = (0..4096).map(|_| samplerz(mu, sigma, sigmin, rng) as i64).collect();
+    let k = 4096 / n;
+    (0..n).map(|i| f0[i * k..(i + 1) * k].iter().sum()).collect()
+}
+
+/// Generates an NTRU basis (f, g, F, G) such that fG - gF = q
+///
+/// f and g are resampled until the Gram-Schmidt norm of the basis is at most
+/// 1.17 * sqrt(q), f is invertible mod q and the basis fits in the secret key
+/// encoding (fg_bits(n) bits for f and g, 8 bits for F and G).
+pub fn ntru_gen<R: RngCore>(n: usize, rng: &mut R) -> (Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) {
+    let fg_lim = 1i64 << (fg_bits(n) - 1);
+    loop {
+        let f = gen_poly(n, rng);
+        let g = gen_poly(n, rng);
+        if f.iter().chain(g.iter()).any(|x| x.abs() >= fg_lim) {
+            continue;
+        }
+        if gs_norm(&f, &g) > 1.17 * 1.17 * Q as f64 {
+            continue;
+        }
+        let f_zq: Vec<u32> = f.iter().map(|&x| x.rem_euclid(Q as i64) as u32).collect();
+        if ntt(&f_zq).contains(&0) {
+            continue;
+        }
+        if let Ok((big_f, big_g)) = ntru_solve(&f, &g)
+            && big_f.iter().chain(big_g.iter()).all(|x| x.abs() <= 127)
+        {
+            return (f, g, big_f, big_g);
+        }
+    }
+}
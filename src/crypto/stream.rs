@@ -0,0 +1,74 @@
+// Büyük mesajlar için parça parça imzalama ve doğrulama (artımlı SHAKE256)
+
+use rand::RngCore;
+use crate::crypto::falcon::{hash_to_point_shake, PublicKey, SecretKey, Signature, VerifyError, SALT_LEN};
+use crate::utils::shake::Shake256;
+
+/// Signs a message given in chunks
+///
+/// The salt is drawn when the signer is created, so that it can be absorbed
+/// before the message as hash-to-point requires.
+#[derive(Clone, Debug)]
+pub struct Signer<'a> {
+    sk: &'a SecretKey,
+    salt: [u8; SALT_LEN],
+    shake: Shake256,
+}
+
+impl<'a> Signer<'a> {
+    pub fn new<R: RngCore>(sk: &'a SecretKey, rng: &mut R) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let mut shake = Shake256::new();
+        shake.update(&salt);
+        Self { sk, salt, shake }
+    }
+
+    /// Absorbs the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.shake.update(chunk);
+    }
+
+    /// Signs the message absorbed so far
+    pub fn finalize_signature<R: RngCore>(self, rng: &mut R) -> Signature {
+        let point = hash_to_point_shake(self.shake, self.sk.n);
+        self.sk.sign_point(&point, self.salt, rng)
+    }
+
+    /// Signs the message absorbed so far, in the padded format of `SecretKey::sign`
+    pub fn finalize<R: RngCore>(self, rng: &mut R) -> Vec<u8> {
+        self.finalize_signature(rng).to_bytes().expect("the signer checks that s2 compresses")
+    }
+}
+
+/// Verifies a signature of a message given in chunks
+///
+/// The signature is decoded first since its salt precedes the message in
+/// hash-to-point.
+#[derive(Clone, Debug)]
+pub struct Verifier<'a> {
+    pk: &'a PublicKey,
+    signature: Signature,
+    shake: Shake256,
+}
+
+impl<'a> Verifier<'a> {
+    pub fn new(pk: &'a PublicKey, signature: &[u8]) -> Result<Self, VerifyError> {
+        // Deterministic signatures are only accepted by `PublicKey::verify_deterministic`
+        let signature = pk.decode_signature(signature, false)?;
+        let mut shake = Shake256::new();
+        shake.update(signature.salt());
+        Ok(Self { pk, signature, shake })
+    }
+
+    /// Absorbs the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.shake.update(chunk);
+    }
+
+    /// Checks the signature against the message absorbed so far
+    pub fn finalize(self) -> Result<(), VerifyError> {
+        let point = hash_to_point_shake(self.shake, self.pk.n);
+        self.pk.verify_point(&point, &self.signature)
+    }
+}
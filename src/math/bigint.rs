@@ -0,0 +1,424 @@
+// Falcon anahtar üretimi için keyfi duyarlıklı tamsayılar (NTRUSolve)
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Shl, Shr, Sub, SubAssign};
+
+/// Integer coefficients the NTRU polynomial arithmetic can run on
+pub trait Coef:
+    Clone
+    + PartialEq
+    + From<i64>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + for<'a> Div<&'a Self, Output = Self>
+    + for<'a> Rem<&'a Self, Output = Self>
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+    + Neg<Output = Self>
+{
+    /// Size of the absolute value in bits, rounded up to a multiple of 8
+    fn bitsize(&self) -> u32;
+}
+
+impl Coef for i64 {
+    fn bitsize(&self) -> u32 {
+        let bits = 64 - self.unsigned_abs().leading_zeros();
+        bits.div_ceil(8) * 8
+    }
+}
+
+/// Signed integer of arbitrary size, stored as sign and magnitude
+///
+/// The magnitude is a little-endian vector of 32-bit limbs without high zero
+/// limbs, so that zero is the empty vector (and is never negative).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    neg: bool,
+    mag: Vec<u32>,
+}
+
+fn trim(v: &mut Vec<u32>) {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+}
+
+fn mag_cmp(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x.cmp(y);
+        }
+    }
+    Ordering::Equal
+}
+
+fn mag_add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for (i, &x) in a.iter().enumerate() {
+        let s = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        res.push(s as u32);
+        carry = s >> 32;
+    }
+    if carry != 0 {
+        res.push(carry as u32);
+    }
+    res
+}
+
+/// Computes a - b, assuming |a| >= |b|
+fn mag_sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &x) in a.iter().enumerate() {
+        let d = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        res.push(d as u32);
+        borrow = (d < 0) as i64;
+    }
+    trim(&mut res);
+    res
+}
+
+fn mag_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + res[i + j] as u64 + carry;
+            res[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    trim(&mut res);
+    res
+}
+
+fn mag_shl(a: &[u32], k: u32) -> Vec<u32> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let limbs = (k / 32) as usize;
+    let bits = k % 32;
+    let mut res = vec![0u32; limbs];
+    if bits == 0 {
+        res.extend_from_slice(a);
+    } else {
+        let mut carry = 0u32;
+        for &x in a {
+            res.push((x << bits) | carry);
+            carry = x >> (32 - bits);
+        }
+        res.push(carry);
+    }
+    trim(&mut res);
+    res
+}
+
+/// Shifts the magnitude right, returning the result and whether nonzero bits were dropped
+fn mag_shr(a: &[u32], k: u32) -> (Vec<u32>, bool) {
+    let limbs = (k / 32) as usize;
+    let bits = k % 32;
+    if limbs >= a.len() {
+        return (Vec::new(), !a.is_empty());
+    }
+    let mut dropped = a[..limbs].iter().any(|&x| x != 0);
+    let mut res = Vec::with_capacity(a.len() - limbs);
+    if bits == 0 {
+        res.extend_from_slice(&a[limbs..]);
+    } else {
+        dropped |= a[limbs] & ((1u32 << bits) - 1) != 0;
+        for i in limbs..a.len() {
+            let hi = if i + 1 < a.len() { a[i + 1] << (32 - bits) } else { 0 };
+            res.push((a[i] >> bits) | hi);
+        }
+    }
+    trim(&mut res);
+    (res, dropped)
+}
+
+/// Divides magnitudes (Knuth's algorithm D), returning quotient and remainder
+fn mag_divmod(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!v.is_empty(), "division by zero");
+    if mag_cmp(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let d = v[0] as u64;
+        let mut q = vec![0u32; u.len()];
+        let mut r = 0u64;
+        for i in (0..u.len()).rev() {
+            let cur = (r << 32) | u[i] as u64;
+            q[i] = (cur / d) as u32;
+            r = cur % d;
+        }
+        trim(&mut q);
+        let mut r = vec![r as u32];
+        trim(&mut r);
+        return (q, r);
+    }
+
+    // Normalize so that the top limb of the divisor has its high bit set
+    let n = v.len();
+    let m = u.len() - n;
+    let s = v[n - 1].leading_zeros();
+    let vn = mag_shl(v, s);
+    let mut un = mag_shl(u, s);
+    un.resize(u.len() + 1, 0);
+
+    let b = 1u64 << 32;
+    let mut q = vec![0u32; m + 1];
+    for j in (0..=m).rev() {
+        let num = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= b || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= b {
+                break;
+            }
+        }
+
+        // Multiply and subtract
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64 + carry;
+            carry = p >> 32;
+            let t = un[i + j] as i64 - borrow - (p & 0xFFFF_FFFF) as i64;
+            un[i + j] = t as u32;
+            borrow = (t < 0) as i64;
+        }
+        let t = un[j + n] as i64 - borrow - carry as i64;
+        un[j + n] = t as u32;
+
+        // The estimate was one too large: add back
+        if t < 0 {
+            qhat -= 1;
+            let mut c = 0u64;
+            for i in 0..n {
+                let s = un[i + j] as u64 + vn[i] as u64 + c;
+                un[i + j] = s as u32;
+                c = s >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(c as u32);
+        }
+        q[j] = qhat as u32;
+    }
+    trim(&mut q);
+    un.truncate(n);
+    trim(&mut un);
+    let (r, _) = mag_shr(&un, s);
+    (q, r)
+}
+
+impl BigInt {
+    fn from_parts(neg: bool, mut mag: Vec<u32>) -> Self {
+        trim(&mut mag);
+        let neg = neg && !mag.is_empty();
+        Self { neg, mag }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    /// Number of significant bits of the absolute value
+    pub fn bits(&self) -> u32 {
+        match self.mag.last() {
+            None => 0,
+            Some(&top) => 32 * (self.mag.len() as u32 - 1) + (32 - top.leading_zeros()),
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self { neg: false, mag: self.mag.clone() }
+    }
+
+    /// Converts to i64, if the value fits
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.mag.len() > 2 {
+            return None;
+        }
+        let m = self.mag.iter().rev().fold(0u64, |acc, &x| (acc << 32) | x as u64);
+        if self.neg {
+            if m <= 1u64 << 63 { Some((m as i64).wrapping_neg()) } else { None }
+        } else {
+            i64::try_from(m).ok()
+        }
+    }
+
+    /// Converts to the nearest-ish f64 (exact when the value fits in 53 bits)
+    pub fn to_f64(&self) -> f64 {
+        let m = self.mag.iter().rev().fold(0.0, |acc, &x| acc * 4294967296.0 + x as f64);
+        if self.neg { -m } else { m }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(x: i64) -> Self {
+        let m = x.unsigned_abs();
+        Self::from_parts(x < 0, vec![m as u32, (m >> 32) as u32])
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => mag_cmp(&self.mag, &other.mag),
+            (true, true) => mag_cmp(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        let neg = !self.neg;
+        BigInt::from_parts(neg, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        self.clone().neg()
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.neg == rhs.neg {
+            return BigInt::from_parts(self.neg, mag_add(&self.mag, &rhs.mag));
+        }
+        match mag_cmp(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(rhs.neg, mag_sub(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.neg, mag_sub(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        if self.neg != rhs.neg {
+            return BigInt::from_parts(self.neg, mag_add(&self.mag, &rhs.mag));
+        }
+        match mag_cmp(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(!self.neg, mag_sub(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.neg, mag_sub(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.neg != rhs.neg, mag_mul(&self.mag, &rhs.mag))
+    }
+}
+
+/// Truncated division, as for the primitive integer types
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> BigInt {
+        let (q, _) = mag_divmod(&self.mag, &rhs.mag);
+        BigInt::from_parts(self.neg != rhs.neg, q)
+    }
+}
+
+/// Remainder of the truncated division, with the sign of the dividend
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        let (_, r) = mag_divmod(&self.mag, &rhs.mag);
+        BigInt::from_parts(self.neg, r)
+    }
+}
+
+macro_rules! forward_binop {
+    ($imp:ident, $method:ident) => {
+        impl $imp<&BigInt> for BigInt {
+            type Output = BigInt;
+            fn $method(self, rhs: &BigInt) -> BigInt {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $imp<BigInt> for BigInt {
+            type Output = BigInt;
+            fn $method(self, rhs: BigInt) -> BigInt {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+forward_binop!(Mul, mul);
+forward_binop!(Div, div);
+forward_binop!(Rem, rem);
+
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: &BigInt) {
+        *self = &*self + rhs;
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        *self = &*self - rhs;
+    }
+}
+
+impl Shl<u32> for &BigInt {
+    type Output = BigInt;
+    fn shl(self, k: u32) -> BigInt {
+        BigInt::from_parts(self.neg, mag_shl(&self.mag, k))
+    }
+}
+
+/// Arithmetic shift, rounding towards minus infinity as for the primitive integer types
+impl Shr<u32> for &BigInt {
+    type Output = BigInt;
+    fn shr(self, k: u32) -> BigInt {
+        let (mag, dropped) = mag_shr(&self.mag, k);
+        if self.neg && dropped {
+            BigInt::from_parts(true, mag_add(&mag, &[1]))
+        } else {
+            BigInt::from_parts(self.neg, mag)
+        }
+    }
+}
+
+impl Coef for BigInt {
+    fn bitsize(&self) -> u32 {
+        self.bits().div_ceil(8) * 8
+    }
+}
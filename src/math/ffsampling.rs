@@ -1,19 +1,164 @@
 // Falcon için FFT tabanlı örnekleme ve Gram matrisi (ffsampling.py'den çevrildi)
+use num_traits::{One, Zero};
+use rand::RngCore;
+use crate::math::fft::{add, adj, adj_fft, add_fft, sub_fft, mul, mul_fft, div_fft, split_fft, merge_fft};
+use crate::math::fpr::{Cplx, FloatOps, Real};
+use crate::math::samplerz::samplerz;
 
-pub fn gram(b: &[Vec<Vec<f64>>]) -> Vec<Vec<Vec<f64>>> {
+/// A matrix of polynomials in FFT representation
+pub type MatrixFft = Vec<Vec<Vec<Cplx>>>;
+
+/// ffLDL tree: the recursive LDL* decomposition of a Gram matrix in FFT representation
+#[derive(Clone, Debug)]
+pub enum LdlTree {
+    /// Inner node: L[1][0] in FFT representation and the two subtrees
+    Node {
+        l10: Vec<Cplx>,
+        t0: Box<LdlTree>,
+        t1: Box<LdlTree>,
+    },
+    /// Leaf: the value of D before normalization, sigma / sqrt(D) after
+    Leaf(Real),
+}
+
+impl LdlTree {
+    /// Returns the leaf values, from left to right
+    pub fn leaves(&self) -> Vec<Real> {
+        match self {
+            LdlTree::Node { t0, t1, .. } => {
+                let mut res = t0.leaves();
+                res.extend(t1.leaves());
+                res
+            }
+            LdlTree::Leaf(v) => vec![*v],
+        }
+    }
+}
+
+/// Computes the Gram matrix B * B^* of a matrix of polynomials
+pub fn gram(b: &[Vec<Vec<Real>>]) -> Vec<Vec<Vec<Real>>> {
     let rows = b.len();
-    let ncols = b[0].len();
     let deg = b[0][0].len();
-    let mut g = vec![vec![vec![0.0; deg]; rows]; rows];
+    let mut g = vec![vec![vec![Real::zero(); deg]; rows]; rows];
     for i in 0..rows {
         for j in 0..rows {
-            for k in 0..ncols {
-                for coef in 0..deg {
-                    g[i][j][coef] += b[i][k][coef] * b[j][k][coef]; // adj fonksiyonu gerekirse eklenir
-                }
+            for (bik, bjk) in b[i].iter().zip(b[j].iter()) {
+                g[i][j] = add(&g[i][j], &mul(bik, &adj(bjk)));
             }
         }
     }
     g
 }
-// Diğer fonksiyonlar (ldl, ldl_fft, ffldl, ffldl_fft, ffnp, ffnp_fft, ffsampling_fft) gerektiğinde eklenebilir. 
\ No newline at end of file
+
+/// Computes the LDL* decomposition of a Gram matrix in FFT representation
+pub fn ldl_fft(g: &[Vec<Vec<Cplx>>]) -> (MatrixFft, MatrixFft) {
+    let deg = g[0][0].len();
+    let dim = g.len();
+    let zero = vec![Cplx::zero(); deg];
+    let one = vec![Cplx::one(); deg];
+    let mut l = vec![vec![zero.clone(); dim]; dim];
+    let mut d = vec![vec![zero; dim]; dim];
+    for i in 0..dim {
+        l[i][i] = one.clone();
+        d[i][i] = g[i][i].clone();
+        for j in 0..i {
+            l[i][j] = g[i][j].clone();
+            for k in 0..j {
+                let tmp = mul_fft(&mul_fft(&l[i][k], &adj_fft(&l[j][k])), &d[k][k]);
+                l[i][j] = sub_fft(&l[i][j], &tmp);
+            }
+            l[i][j] = div_fft(&l[i][j], &d[j][j]);
+            let tmp = mul_fft(&mul_fft(&l[i][j], &adj_fft(&l[i][j])), &d[j][j]);
+            d[i][i] = sub_fft(&d[i][i], &tmp);
+        }
+    }
+    (l, d)
+}
+
+/// Computes the ffLDL tree of a 2x2 Gram matrix in FFT representation
+pub fn ffldl_fft(g: &[Vec<Vec<Cplx>>]) -> LdlTree {
+    let n = g[0][0].len();
+    let (l, d) = ldl_fft(g);
+    if n > 2 {
+        let (d00, d01) = split_fft(&d[0][0]);
+        let (d10, d11) = split_fft(&d[1][1]);
+        let g0 = vec![vec![d00.clone(), d01.clone()], vec![adj_fft(&d01), d00]];
+        let g1 = vec![vec![d10.clone(), d11.clone()], vec![adj_fft(&d11), d10]];
+        LdlTree::Node {
+            l10: l[1][0].clone(),
+            t0: Box::new(ffldl_fft(&g0)),
+            t1: Box::new(ffldl_fft(&g1)),
+        }
+    } else {
+        LdlTree::Node {
+            l10: l[1][0].clone(),
+            t0: Box::new(LdlTree::Leaf(d[0][0][0].re)),
+            t1: Box::new(LdlTree::Leaf(d[1][1][0].re)),
+        }
+    }
+}
+
+/// Replaces every leaf value `v` of the tree by `sigma / sqrt(v)`
+pub fn normalize_tree(tree: &mut LdlTree, sigma: Real) {
+    match tree {
+        LdlTree::Node { t0, t1, .. } => {
+            normalize_tree(t0, sigma);
+            normalize_tree(t1, sigma);
+        }
+        LdlTree::Leaf(v) => {
+            *v = sigma / v.sqrt();
+        }
+    }
+}
+
+/// Computes the ffSampling of a target t (FFT representation) over a normalized ffLDL tree
+pub fn ffsampling_fft<R: RngCore>(
+    t: &[Vec<Cplx>; 2],
+    tree: &LdlTree,
+    sigmin: Real,
+    rng: &mut R,
+) -> [Vec<Cplx>; 2] {
+    match tree {
+        LdlTree::Node { l10, t0, t1 } => {
+            let (t10, t11) = split_fft(&t[1]);
+            let [z10, z11] = ffsampling_fft(&[t10, t11], t1, sigmin, rng);
+            let z1 = merge_fft(&z10, &z11);
+            let t0b = add_fft(&t[0], &mul_fft(&sub_fft(&t[1], &z1), l10));
+            let (t00, t01) = split_fft(&t0b);
+            let [z00, z01] = ffsampling_fft(&[t00, t01], t0, sigmin, rng);
+            let z0 = merge_fft(&z00, &z01);
+            [z0, z1]
+        }
+        LdlTree::Leaf(sigma) => {
+            let z0 = samplerz(t[0][0].re, *sigma, sigmin, rng);
+            let z1 = samplerz(t[1][0].re, *sigma, sigmin, rng);
+            [
+                vec![Cplx::new(Real::of_i64(z0 as i64), Real::zero())],
+                vec![Cplx::new(Real::of_i64(z1 as i64), Real::zero())],
+            ]
+        }
+    }
+}
+
+/// Computes the ffNP (fast Fourier nearest plane) of a target t over an ffLDL tree
+///
+/// This is ffSampling with the Gaussian sampler replaced by rounding, so it is
+/// deterministic and independent of the leaf values.
+pub fn ffnp_fft(t: &[Vec<Cplx>; 2], tree: &LdlTree) -> [Vec<Cplx>; 2] {
+    match tree {
+        LdlTree::Node { l10, t0, t1 } => {
+            let (t10, t11) = split_fft(&t[1]);
+            let [z10, z11] = ffnp_fft(&[t10, t11], t1);
+            let z1 = merge_fft(&z10, &z11);
+            let t0b = add_fft(&t[0], &mul_fft(&sub_fft(&t[1], &z1), l10));
+            let (t00, t01) = split_fft(&t0b);
+            let [z00, z01] = ffnp_fft(&[t00, t01], t0);
+            let z0 = merge_fft(&z00, &z01);
+            [z0, z1]
+        }
+        LdlTree::Leaf(_) => [
+            vec![Cplx::new(Real::of_i64(t[0][0].re.rint()), Real::zero())],
+            vec![Cplx::new(Real::of_i64(t[1][0].re.rint()), Real::zero())],
+        ],
+    }
+}
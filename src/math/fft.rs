@@ -1,51 +1,59 @@
 // Falcon için FFT işlemleri (fft.py'den çevrildi)
 use num_complex::Complex64;
-use std::ops::Neg;
+use num_traits::Zero;
 use crate::constants::fft_constants::get_roots_dict;
+use crate::math::fpr::{Cplx, FloatOps, Real};
 
-pub fn split_fft(f_fft: &[Complex64]) -> (Vec<Complex64>, Vec<Complex64>) {
+/// Converts an entry of the root tables to the working precision
+fn root(w: Complex64) -> Cplx {
+    Cplx::new(Real::of_f64(w.re), Real::of_f64(w.im))
+}
+
+pub fn split_fft(f_fft: &[Cplx]) -> (Vec<Cplx>, Vec<Cplx>) {
     let n = f_fft.len();
     let dict = get_roots_dict();
     let w = dict.get(&n).expect("roots not found");
-    let mut f0_fft = vec![Complex64::new(0.0, 0.0); n / 2];
-    let mut f1_fft = vec![Complex64::new(0.0, 0.0); n / 2];
+    let half = Real::of_f64(0.5);
+    let mut f0_fft = vec![Cplx::zero(); n / 2];
+    let mut f1_fft = vec![Cplx::zero(); n / 2];
     for i in 0..n / 2 {
-        f0_fft[i] = 0.5 * (f_fft[2 * i] + f_fft[2 * i + 1]);
-        f1_fft[i] = 0.5 * (f_fft[2 * i] - f_fft[2 * i + 1]) * w[2 * i].conj();
+        f0_fft[i] = (f_fft[2 * i] + f_fft[2 * i + 1]) * half;
+        f1_fft[i] = (f_fft[2 * i] - f_fft[2 * i + 1]) * half * root(w[2 * i]).conj();
     }
     (f0_fft, f1_fft)
 }
 
-pub fn merge_fft(f0_fft: &[Complex64], f1_fft: &[Complex64]) -> Vec<Complex64> {
+pub fn merge_fft(f0_fft: &[Cplx], f1_fft: &[Cplx]) -> Vec<Cplx> {
     let n = 2 * f0_fft.len();
     let dict = get_roots_dict();
     let w = dict.get(&n).expect("roots not found");
-    let mut f_fft = vec![Complex64::new(0.0, 0.0); n];
+    let mut f_fft = vec![Cplx::zero(); n];
     for i in 0..n / 2 {
-        f_fft[2 * i] = f0_fft[i] + w[2 * i] * f1_fft[i];
-        f_fft[2 * i + 1] = f0_fft[i] - w[2 * i] * f1_fft[i];
+        let wf1 = root(w[2 * i]) * f1_fft[i];
+        f_fft[2 * i] = f0_fft[i] + wf1;
+        f_fft[2 * i + 1] = f0_fft[i] - wf1;
     }
     f_fft
 }
 
 // Gerçek FFT implementasyonu
-pub fn fft(f: &[f64]) -> Vec<Complex64> {
+pub fn fft(f: &[Real]) -> Vec<Cplx> {
     let n = f.len();
     if n == 1 {
-        return vec![Complex64::new(f[0], 0.0)];
+        return vec![Cplx::new(f[0], Real::zero())];
     }
     
     if n == 2 {
         let f_fft = vec![
-            Complex64::new(f[0] + f[1], 0.0),
-            Complex64::new(f[0] - f[1], 0.0),
+            Cplx::new(f[0], f[1]),
+            Cplx::new(f[0], -f[1]),
         ];
         return f_fft;
     }
     
     // Recursive FFT
-    let mut f0 = vec![0.0; n / 2];
-    let mut f1 = vec![0.0; n / 2];
+    let mut f0 = vec![Real::zero(); n / 2];
+    let mut f1 = vec![Real::zero(); n / 2];
     
     for i in 0..n / 2 {
         f0[i] = f[2 * i];
@@ -58,24 +66,21 @@ pub fn fft(f: &[f64]) -> Vec<Complex64> {
     merge_fft(&f0_fft, &f1_fft)
 }
 
-pub fn ifft(f_fft: &[Complex64]) -> Vec<f64> {
+pub fn ifft(f_fft: &[Cplx]) -> Vec<Real> {
     let n = f_fft.len();
     if n == 1 {
         return vec![f_fft[0].re];
     }
     
     if n == 2 {
-        return vec![
-            0.5 * (f_fft[0].re + f_fft[1].re),
-            0.5 * (f_fft[0].re - f_fft[1].re),
-        ];
+        return vec![f_fft[0].re, f_fft[0].im];
     }
     
     let (f0_fft, f1_fft) = split_fft(f_fft);
     let f0 = ifft(&f0_fft);
     let f1 = ifft(&f1_fft);
     
-    let mut f = vec![0.0; n];
+    let mut f = vec![Real::zero(); n];
     for i in 0..n / 2 {
         f[2 * i] = f0[i];
         f[2 * i + 1] = f1[i];
@@ -83,27 +88,27 @@ pub fn ifft(f_fft: &[Complex64]) -> Vec<f64> {
     f
 }
 
-pub fn add(f: &[f64], g: &[f64]) -> Vec<f64> {
+pub fn add(f: &[Real], g: &[Real]) -> Vec<Real> {
     assert_eq!(f.len(), g.len());
-    f.iter().zip(g.iter()).map(|(a, b)| a + b).collect()
+    f.iter().zip(g.iter()).map(|(&a, &b)| a + b).collect()
 }
 
-pub fn sub(f: &[f64], g: &[f64]) -> Vec<f64> {
+pub fn sub(f: &[Real], g: &[Real]) -> Vec<Real> {
     assert_eq!(f.len(), g.len());
-    f.iter().zip(g.iter()).map(|(a, b)| a - b).collect()
+    f.iter().zip(g.iter()).map(|(&a, &b)| a - b).collect()
 }
 
-pub fn neg(f: &[f64]) -> Vec<f64> {
+pub fn neg(f: &[Real]) -> Vec<Real> {
     f.iter().map(|&x| -x).collect()
 }
 
-pub fn mul(f: &[f64], g: &[f64]) -> Vec<f64> {
+pub fn mul(f: &[Real], g: &[Real]) -> Vec<Real> {
     let n = f.len();
     assert_eq!(n, g.len());
     
     let f_fft = fft(f);
     let g_fft = fft(g);
-    let mut h_fft = vec![Complex64::new(0.0, 0.0); n];
+    let mut h_fft = vec![Cplx::zero(); n];
     
     for i in 0..n {
         h_fft[i] = f_fft[i] * g_fft[i];
@@ -112,68 +117,63 @@ pub fn mul(f: &[f64], g: &[f64]) -> Vec<f64> {
     ifft(&h_fft)
 }
 
-pub fn div(f: &[f64], g: &[f64]) -> Vec<f64> {
+/// Divides f by g in R[x] / (x^n + 1)
+///
+/// g must be invertible, i.e. its FFT has no zero coefficient. This is not
+/// checked in release builds: the quotient is then meaningless, and under the
+/// `fpr` backend division by zero is not supported.
+pub fn div(f: &[Real], g: &[Real]) -> Vec<Real> {
     let n = f.len();
     assert_eq!(n, g.len());
     
     let f_fft = fft(f);
     let g_fft = fft(g);
-    let mut h_fft = vec![Complex64::new(0.0, 0.0); n];
+    debug_assert!(g_fft.iter().all(|b| !b.is_zero()), "division by a non-invertible polynomial");
+    let mut h_fft = vec![Cplx::zero(); n];
     
     for i in 0..n {
-        if g_fft[i].norm() > 1e-10 {
-            h_fft[i] = f_fft[i] / g_fft[i];
-        } else {
-            h_fft[i] = Complex64::new(0.0, 0.0);
-        }
+        h_fft[i] = f_fft[i] / g_fft[i];
     }
     
     ifft(&h_fft)
 }
 
 // FFT domain operations
-pub fn add_fft(f_fft: &[Complex64], g_fft: &[Complex64]) -> Vec<Complex64> {
+pub fn add_fft(f_fft: &[Cplx], g_fft: &[Cplx]) -> Vec<Cplx> {
     assert_eq!(f_fft.len(), g_fft.len());
     f_fft.iter().zip(g_fft.iter()).map(|(a, b)| a + b).collect()
 }
 
-pub fn sub_fft(f_fft: &[Complex64], g_fft: &[Complex64]) -> Vec<Complex64> {
+pub fn sub_fft(f_fft: &[Cplx], g_fft: &[Cplx]) -> Vec<Cplx> {
     assert_eq!(f_fft.len(), g_fft.len());
     f_fft.iter().zip(g_fft.iter()).map(|(a, b)| a - b).collect()
 }
 
-pub fn mul_fft(f_fft: &[Complex64], g_fft: &[Complex64]) -> Vec<Complex64> {
+pub fn mul_fft(f_fft: &[Cplx], g_fft: &[Cplx]) -> Vec<Cplx> {
     assert_eq!(f_fft.len(), g_fft.len());
     f_fft.iter().zip(g_fft.iter()).map(|(a, b)| a * b).collect()
 }
 
-pub fn div_fft(f_fft: &[Complex64], g_fft: &[Complex64]) -> Vec<Complex64> {
+/// Divides f by g in FFT representation
+///
+/// Every coefficient of g_fft must be non-zero, which is only checked in debug
+/// builds, see `div`.
+pub fn div_fft(f_fft: &[Cplx], g_fft: &[Cplx]) -> Vec<Cplx> {
     assert_eq!(f_fft.len(), g_fft.len());
-    f_fft.iter().zip(g_fft.iter()).map(|(a, b)| {
-        if b.norm() > 1e-10 {
-            a / b
-        } else {
-            Complex64::new(0.0, 0.0)
-        }
-    }).collect()
+    debug_assert!(g_fft.iter().all(|b| !b.is_zero()), "division by zero in FFT representation");
+    f_fft.iter().zip(g_fft.iter()).map(|(a, b)| a / b).collect()
 }
 
-pub fn adj_fft(f_fft: &[Complex64]) -> Vec<Complex64> {
-    let n = f_fft.len();
-    let mut result = vec![Complex64::new(0.0, 0.0); n];
-    result[0] = f_fft[0].conj();
-    for i in 1..n {
-        result[i] = f_fft[n - i].conj();
-    }
-    result
+pub fn adj_fft(f_fft: &[Cplx]) -> Vec<Cplx> {
+    f_fft.iter().map(|a| a.conj()).collect()
 }
 
-pub fn adj(f: &[f64]) -> Vec<f64> {
+pub fn adj(f: &[Real]) -> Vec<Real> {
     let n = f.len();
-    let mut result = vec![0.0; n];
+    let mut result = vec![Real::zero(); n];
     result[0] = f[0];
     for i in 1..n {
-        result[i] = f[n - i];
+        result[i] = -f[n - i];
     }
     result
 } 
\ No newline at end of file
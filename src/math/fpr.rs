@@ -0,0 +1,465 @@
+// Falcon için tamsayı tabanlı kayan nokta emülasyonu (fpr.c'den çevrildi)
+//
+// Values are IEEE-754 binary64 patterns, and every operation is computed with
+// integer arithmetic only and without data-dependent branches. Results are
+// correctly rounded (round to nearest, ties to even) for normal values, hence
+// bit-identical to native f64. As in the reference implementation, operands
+// are assumed to be finite, subnormals are flushed to zero and division by
+// zero or square root of a negative value are not supported.
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num_complex::Complex;
+use num_traits::{Num, One, Zero};
+
+/// Floating-point number emulated with integer operations
+#[derive(Clone, Copy, Default)]
+pub struct Fpr(u64);
+
+/// Floating-point operations shared by the native and the emulated backend
+pub trait FloatOps: Copy + Num + Neg<Output = Self> + PartialOrd + fmt::Debug {
+    /// Converts an integer, rounding to nearest
+    fn of_i64(i: i64) -> Self;
+    /// Converts a native double (exact)
+    fn of_f64(x: f64) -> Self;
+    /// Converts to a native double (exact)
+    fn to_f64(self) -> f64;
+    fn sqrt(self) -> Self;
+    /// Rounds to the nearest integer, ties to even
+    fn rint(self) -> i64;
+    /// Rounds towards minus infinity
+    fn floor_int(self) -> i64;
+    /// Rounds towards zero
+    fn trunc_int(self) -> i64;
+}
+
+/// Real numbers used by the FFT, the ffLDL tree and the sampler
+#[cfg(not(feature = "fpr"))]
+pub type Real = f64;
+/// Real numbers used by the FFT, the ffLDL tree and the sampler
+#[cfg(feature = "fpr")]
+pub type Real = Fpr;
+
+/// Complex numbers over `Real`
+pub type Cplx = Complex<Real>;
+
+impl FloatOps for f64 {
+    fn of_i64(i: i64) -> Self {
+        i as f64
+    }
+
+    fn of_f64(x: f64) -> Self {
+        x
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn rint(self) -> i64 {
+        self.round_ties_even() as i64
+    }
+
+    fn floor_int(self) -> i64 {
+        self.floor() as i64
+    }
+
+    fn trunc_int(self) -> i64 {
+        self as i64
+    }
+}
+
+const M52: u64 = (1 << 52) - 1;
+
+/// Normalizes m so that its top bit is set, adjusting e so that m * 2^e is kept
+fn norm64(m: &mut u64, e: &mut i32) {
+    *e -= 63;
+    for k in [32u32, 16, 8, 4, 2, 1] {
+        let nt = (*m >> (64 - k)) as u32;
+        let nt = (nt | nt.wrapping_neg()) >> 31;
+        *m ^= (*m ^ (*m << k)) & (nt as u64).wrapping_sub(1);
+        *e += (nt << k.trailing_zeros()) as i32;
+    }
+}
+
+impl Fpr {
+    pub const fn from_bits(bits: u64) -> Self {
+        Fpr(bits)
+    }
+
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Packs (-1)^s * 2^e * m with m in 2^54..2^55-1 (or 0); the two low bits
+    /// of m are the rounding bits, the lowest one being sticky
+    fn make(s: u64, e: i32, m: u64) -> Self {
+        let e = e + 1076;
+        let t = (e as u32) >> 31;
+        let m = m & (t as u64).wrapping_sub(1);
+        let t = (m >> 54) as u32;
+        let e = (e & (t as i32).wrapping_neg()) as u32;
+        let x = ((s << 63) | (m >> 2)).wrapping_add((e as u64) << 52);
+        let f = (m as u32) & 7;
+        Fpr(x + ((0xC8u32 >> f) & 1) as u64)
+    }
+
+    /// Returns i * 2^sc
+    pub fn scaled(i: i64, sc: i32) -> Self {
+        let s = (i as u64) >> 63;
+        let i = (i ^ (s as i64).wrapping_neg()).wrapping_add(s as i64);
+        let mut m = i as u64;
+        let mut e = 9 + sc;
+        norm64(&mut m, &mut e);
+        m |= ((m as u32 & 0x1FF) + 0x1FF) as u64;
+        m >>= 9;
+        let t = ((i | i.wrapping_neg()) as u64 >> 63) as u32;
+        m &= (t as u64).wrapping_neg();
+        e &= (t as i32).wrapping_neg();
+        Self::make(s, e, m)
+    }
+
+    fn fpr_add(self, other: Self) -> Self {
+        let (mut x, mut y) = (self.0, other.0);
+        // Swap so that |x| >= |y|; if |x| = |y| the positive one comes first
+        let m = (1u64 << 63) - 1;
+        let za = (x & m).wrapping_sub(y & m);
+        let cs = (za >> 63) as u32 | ((1 - (za.wrapping_neg() >> 63) as u32) & (x >> 63) as u32);
+        let m = (x ^ y) & (cs as u64).wrapping_neg();
+        x ^= m;
+        y ^= m;
+
+        // Mantissas scaled to 2^55..2^56-1, zero operands get a zero mantissa
+        let mut ex = (x >> 52) as i32;
+        let sx = ex >> 11;
+        ex &= 0x7FF;
+        let m = (((ex + 0x7FF) >> 11) as u64) << 52;
+        let mut xu = ((x & M52) | m) << 3;
+        ex -= 1078;
+        let mut ey = (y >> 52) as i32;
+        let sy = ey >> 11;
+        ey &= 0x7FF;
+        let m = (((ey + 0x7FF) >> 11) as u64) << 52;
+        let mut yu = ((y & M52) | m) << 3;
+        ey -= 1078;
+
+        // Align y on x, the lowest bit being sticky
+        let mut cc = ex - ey;
+        yu &= ((((cc - 60) as u32) >> 31) as u64).wrapping_neg();
+        cc &= 63;
+        let m = (1u64 << cc) - 1;
+        yu |= (yu & m) + m;
+        yu >>= cc;
+
+        xu = xu.wrapping_add(yu.wrapping_sub((yu << 1) & (((sx ^ sy) as u64) & 1).wrapping_neg()));
+        norm64(&mut xu, &mut ex);
+        xu |= ((xu as u32 & 0x1FF) + 0x1FF) as u64;
+        xu >>= 9;
+        ex += 9;
+        Self::make((sx & 1) as u64, ex, xu)
+    }
+
+    fn fpr_mul(self, other: Self) -> Self {
+        let (x, y) = (self.0, other.0);
+        let xu = (x & M52) | (1 << 52);
+        let yu = (y & M52) | (1 << 52);
+
+        // 53 x 53 bit product with 25-bit low limbs
+        let x0 = xu as u32 & 0x01FF_FFFF;
+        let x1 = (xu >> 25) as u32;
+        let y0 = yu as u32 & 0x01FF_FFFF;
+        let y1 = (yu >> 25) as u32;
+        let w = x0 as u64 * y0 as u64;
+        let z0 = w as u32 & 0x01FF_FFFF;
+        let mut z1 = (w >> 25) as u32;
+        let w = x0 as u64 * y1 as u64;
+        z1 += w as u32 & 0x01FF_FFFF;
+        let mut z2 = (w >> 25) as u32;
+        let w = x1 as u64 * y0 as u64;
+        z1 += w as u32 & 0x01FF_FFFF;
+        z2 += (w >> 25) as u32;
+        let mut zu = x1 as u64 * y1 as u64;
+        z2 += z1 >> 25;
+        z1 &= 0x01FF_FFFF;
+        zu += z2 as u64;
+
+        // Keep 55 or 56 bits with a sticky bit, then normalize to 55 bits
+        zu |= (((z0 | z1) + 0x01FF_FFFF) >> 25) as u64;
+        let zv = (zu >> 1) | (zu & 1);
+        let w = zu >> 55;
+        zu ^= (zu ^ zv) & w.wrapping_neg();
+
+        let ex = ((x >> 52) & 0x7FF) as i32;
+        let ey = ((y >> 52) & 0x7FF) as i32;
+        let e = ex + ey - 2100 + w as i32;
+        let s = (x ^ y) >> 63;
+        let d = ((ex + 0x7FF) & (ey + 0x7FF)) >> 11;
+        zu &= (d as u64).wrapping_neg();
+        Self::make(s, e, zu)
+    }
+
+    fn fpr_div(self, other: Self) -> Self {
+        let (x, y) = (self.0, other.0);
+        let mut xu = (x & M52) | (1 << 52);
+        let yu = (y & M52) | (1 << 52);
+
+        // Bit-by-bit division, 55 quotient bits
+        let mut q = 0u64;
+        for _ in 0..55 {
+            let b = (xu.wrapping_sub(yu) >> 63).wrapping_sub(1);
+            xu -= b & yu;
+            q |= b & 1;
+            xu <<= 1;
+            q <<= 1;
+        }
+        q |= (xu | xu.wrapping_neg()) >> 63;
+        let q2 = (q >> 1) | (q & 1);
+        let w = q >> 55;
+        q ^= (q ^ q2) & w.wrapping_neg();
+
+        let ex = ((x >> 52) & 0x7FF) as i32;
+        let ey = ((y >> 52) & 0x7FF) as i32;
+        let mut e = ex - ey - 55 + w as i32;
+        let mut s = (x ^ y) >> 63;
+        let d = (ex + 0x7FF) >> 11;
+        s &= d as u64;
+        e &= d.wrapping_neg();
+        q &= (d as u64).wrapping_neg();
+        Self::make(s, e, q)
+    }
+
+    fn fpr_sqrt(self) -> Self {
+        let x = self.0;
+        let mut xu = (x & M52) | (1 << 52);
+        let ex = ((x >> 52) & 0x7FF) as i32;
+        let mut e = ex - 1023;
+
+        // Make the exponent even, then compute the root bit by bit
+        xu += xu & ((e & 1) as u64).wrapping_neg();
+        e >>= 1;
+        xu <<= 1;
+        let mut q = 0u64;
+        let mut s = 0u64;
+        let mut r = 1u64 << 53;
+        for _ in 0..54 {
+            let t = s + r;
+            let b = (xu.wrapping_sub(t) >> 63).wrapping_sub(1);
+            s += (r << 1) & b;
+            xu -= t & b;
+            q += r & b;
+            xu <<= 1;
+            r >>= 1;
+        }
+        q <<= 1;
+        q |= (xu | xu.wrapping_neg()) >> 63;
+        e -= 54;
+        q &= (((ex + 0x7FF) >> 11) as u64).wrapping_neg();
+        Self::make(0, e, q)
+    }
+
+    fn fpr_rint(self) -> i64 {
+        let x = self.0;
+        let mut m = ((x << 10) | (1 << 62)) & ((1 << 63) - 1);
+        let mut e = 1085 - ((x >> 52) & 0x7FF) as i32;
+        m &= ((((e - 64) as u32) >> 31) as u64).wrapping_neg();
+        e &= 63;
+
+        // Dropped bits and lowest kept bit, shrunk to three bits (last one sticky)
+        let d = m.wrapping_shl((63 - e) as u32);
+        let dd = d as u32 | ((d >> 32) as u32 & 0x1FFF_FFFF);
+        let f = (d >> 61) as u32 | ((dd | dd.wrapping_neg()) >> 31);
+        let m = (m >> e) + ((0xC8u32 >> f) & 1) as u64;
+        let s = (x >> 63) as i64;
+        ((m as i64) ^ s.wrapping_neg()).wrapping_add(s)
+    }
+
+    fn fpr_floor(self) -> i64 {
+        let x = self.0;
+        let e = ((x >> 52) & 0x7FF) as i32;
+        let t = (x >> 63) as i64;
+        let mut xi = (((x << 10) | (1 << 62)) & ((1 << 63) - 1)) as i64;
+        xi = (xi ^ t.wrapping_neg()).wrapping_add(t);
+        let cc = 1085 - e;
+        xi >>= cc & 63;
+        xi ^= (xi ^ t.wrapping_neg()) & ((((63 - cc) as u32) >> 31) as i64).wrapping_neg();
+        xi
+    }
+
+    fn fpr_trunc(self) -> i64 {
+        let x = self.0;
+        let e = ((x >> 52) & 0x7FF) as i32;
+        let mut xu = ((x << 10) | (1 << 62)) & ((1 << 63) - 1);
+        let cc = 1085 - e;
+        xu >>= cc & 63;
+        xu &= ((((cc - 64) as u32) >> 31) as u64).wrapping_neg();
+        let t = x >> 63;
+        (xu ^ t.wrapping_neg()).wrapping_add(t) as i64
+    }
+
+    /// Maps the sign-magnitude bits to an integer that orders like the value,
+    /// both zeros mapping to 0
+    fn order_key(self) -> i64 {
+        let m = (self.0 as i64) >> 63;
+        ((self.0 & !(1 << 63)) as i64 ^ m).wrapping_sub(m)
+    }
+}
+
+impl FloatOps for Fpr {
+    fn of_i64(i: i64) -> Self {
+        Fpr::scaled(i, 0)
+    }
+
+    fn of_f64(x: f64) -> Self {
+        Fpr(x.to_bits())
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+
+    fn sqrt(self) -> Self {
+        self.fpr_sqrt()
+    }
+
+    fn rint(self) -> i64 {
+        self.fpr_rint()
+    }
+
+    fn floor_int(self) -> i64 {
+        self.fpr_floor()
+    }
+
+    fn trunc_int(self) -> i64 {
+        self.fpr_trunc()
+    }
+}
+
+impl fmt::Debug for Fpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+/// Zeros of both signs compare equal, as in IEEE-754
+impl PartialEq for Fpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 || ((self.0 | other.0) << 1) == 0
+    }
+}
+
+/// Compares the integer keys of the operands, without branching on them
+impl PartialOrd for Fpr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.order_key().cmp(&other.order_key()))
+    }
+
+    fn lt(&self, other: &Self) -> bool {
+        self.order_key() < other.order_key()
+    }
+
+    fn le(&self, other: &Self) -> bool {
+        self.order_key() <= other.order_key()
+    }
+
+    fn gt(&self, other: &Self) -> bool {
+        self.order_key() > other.order_key()
+    }
+
+    fn ge(&self, other: &Self) -> bool {
+        self.order_key() >= other.order_key()
+    }
+}
+
+impl Neg for Fpr {
+    type Output = Fpr;
+    fn neg(self) -> Fpr {
+        Fpr(self.0 ^ (1 << 63))
+    }
+}
+
+impl Add for Fpr {
+    type Output = Fpr;
+    fn add(self, other: Fpr) -> Fpr {
+        self.fpr_add(other)
+    }
+}
+
+impl Sub for Fpr {
+    type Output = Fpr;
+    fn sub(self, other: Fpr) -> Fpr {
+        self.fpr_add(-other)
+    }
+}
+
+impl Mul for Fpr {
+    type Output = Fpr;
+    fn mul(self, other: Fpr) -> Fpr {
+        self.fpr_mul(other)
+    }
+}
+
+impl Div for Fpr {
+    type Output = Fpr;
+    fn div(self, other: Fpr) -> Fpr {
+        self.fpr_div(other)
+    }
+}
+
+/// Remainder of the division truncated towards zero
+impl Rem for Fpr {
+    type Output = Fpr;
+    fn rem(self, other: Fpr) -> Fpr {
+        self - other * Fpr::of_i64((self / other).trunc_int())
+    }
+}
+
+impl AddAssign for Fpr {
+    fn add_assign(&mut self, other: Fpr) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fpr {
+    fn sub_assign(&mut self, other: Fpr) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fpr {
+    fn mul_assign(&mut self, other: Fpr) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Fpr {
+    fn div_assign(&mut self, other: Fpr) {
+        *self = *self / other;
+    }
+}
+
+impl Zero for Fpr {
+    fn zero() -> Self {
+        Fpr(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        (self.0 << 1) == 0
+    }
+}
+
+impl One for Fpr {
+    fn one() -> Self {
+        Fpr(0x3FF0_0000_0000_0000)
+    }
+}
+
+impl Num for Fpr {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Fpr::of_f64)
+    }
+}
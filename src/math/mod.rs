@@ -5,14 +5,20 @@
 //! - Number Theoretic Transform (NTT) 
 //! - Gaussian sampling
 //! - Fast Fourier sampling
+//! - Arbitrary precision integers for key generation
+//! - Integer-only floating point emulation (`fpr` feature)
 
 pub mod fft;
 pub mod ntt;
 pub mod ffsampling;
 pub mod samplerz;
+pub mod bigint;
+pub mod fpr;
 
 // Re-export commonly used items
 pub use fft::*;
 pub use ntt::*;
 pub use ffsampling::*;
-pub use samplerz::*; 
\ No newline at end of file
+pub use samplerz::*;
+pub use bigint::*;
+pub use fpr::*; 
\ No newline at end of file
@@ -3,6 +3,8 @@ use crate::utils::common::Q;
 use crate::constants::ntt_constants::{get_roots_dict, get_inv_mod_q};
 
 const I2: u32 = 6145;
+/// Square root of -1 mod q, i.e. the first root of x^2 + 1
+const SQR1: u32 = 1479;
 
 pub fn split_ntt(f_ntt: &[u32]) -> (Vec<u32>, Vec<u32>) {
     let n = f_ntt.len();
@@ -43,9 +45,10 @@ pub fn ntt(f: &[u32]) -> Vec<u32> {
     }
     
     if n == 2 {
+        let prod = (SQR1 as u64 * f[1] as u64) % Q as u64;
         return vec![
-            ((f[0] as u64 + f[1] as u64) % Q as u64) as u32,
-            ((f[0] as u64 + Q as u64 - f[1] as u64) % Q as u64) as u32,
+            ((f[0] as u64 + prod) % Q as u64) as u32,
+            ((f[0] as u64 + Q as u64 - prod) % Q as u64) as u32,
         ];
     }
     
@@ -71,9 +74,11 @@ pub fn intt(f_ntt: &[u32]) -> Vec<u32> {
     }
     
     if n == 2 {
+        let inv_mod_q = get_inv_mod_q();
+        let diff = (f_ntt[0] as u64 + Q as u64 - f_ntt[1] as u64) % Q as u64;
         return vec![
             ((I2 as u64 * (f_ntt[0] as u64 + f_ntt[1] as u64)) % Q as u64) as u32,
-            ((I2 as u64 * (f_ntt[0] as u64 + Q as u64 - f_ntt[1] as u64)) % Q as u64) as u32,
+            ((I2 as u64 * diff % Q as u64) * inv_mod_q[SQR1 as usize] as u64 % Q as u64) as u32,
         ];
     }
     
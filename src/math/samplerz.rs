@@ -1,11 +1,13 @@
 // Falcon için Gaussian örnekleyici (samplerz.py'den çevrildi)
 
-use std::f64;
+use crate::math::fpr::{FloatOps, Real};
 
 pub const MAX_SIGMA: f64 = 1.8205;
 pub const INV_2SIGMA2: f64 = 1.0 / (2.0 * (MAX_SIGMA * MAX_SIGMA));
 pub const RCDT_PREC: usize = 72;
+#[allow(clippy::approx_constant)]
 pub const LN2: f64 = 0.69314718056;
+#[allow(clippy::approx_constant)]
 pub const ILN2: f64 = 1.44269504089;
 
 pub const RCDT: [u128; 18] = [
@@ -64,26 +66,33 @@ pub fn basesampler<R: RngCore>(rng: &mut R) -> u32 {
     z0
 }
 
-pub fn approxexp(x: f64, ccs: f64) -> u64 {
+pub fn approxexp(x: Real, ccs: Real) -> u64 {
+    let two63 = Real::of_f64((1u64 << 63) as f64);
     let mut y = C[0] as i128;
-    let z = (x * (1u64 << 63) as f64) as i128;
+    let z = (x * two63).trunc_int() as i128;
     for &elt in &C[1..] {
         y = elt as i128 - ((z * y) >> 63);
     }
-    let z2 = ((ccs * (1u64 << 63) as f64) as i128) << 1;
-    let y2 = (z2 * y) >> 63;
+    let z2 = ((ccs * two63).trunc_int() as u128) << 1;
+    let y2 = (z2 * y as u128) >> 63;
     y2 as u64
 }
 
-pub fn berexp<R: RngCore>(x: f64, ccs: f64, rng: &mut R) -> bool {
-    let mut s = (x * ILN2) as i32;
-    let r = x - (s as f64) * LN2;
+pub fn berexp<R: RngCore>(x: Real, ccs: Real, rng: &mut R) -> bool {
+    // x >= 0 as long as sigma <= MAX_SIGMA; clamping keeps s >= 0 otherwise,
+    // so that the shift below stays defined. Both backends hold the bits of an
+    // f64, so a negative x is masked to +0 from its sign bit without branching.
+    let bits = x.to_f64().to_bits();
+    let x = Real::of_f64(f64::from_bits(bits & !(((bits as i64) >> 63) as u64)));
+    let mut s = (x * Real::of_f64(ILN2)).trunc_int() as i32;
+    let r = x - Real::of_i64(s as i64) * Real::of_f64(LN2);
     s = s.min(63);
-    let z = ((approxexp(r, ccs) - 1) >> s) as u64;
+    let z = (approxexp(r, ccs) - 1) >> s;
     let mut w = 0i32;
     for i in (0..=56).rev().step_by(8) {
-        let p = rng.next_u32() as u8;
-        w = p as i32 - (((z >> i) & 0xFF) as i32);
+        let mut p = [0u8; 1];
+        rng.fill_bytes(&mut p);
+        w = p[0] as i32 - (((z >> i) & 0xFF) as i32);
         if w != 0 {
             break;
         }
@@ -91,20 +100,20 @@ pub fn berexp<R: RngCore>(x: f64, ccs: f64, rng: &mut R) -> bool {
     w < 0
 }
 
-pub fn samplerz<R: RngCore>(mu: f64, sigma: f64, sigmin: f64, rng: &mut R) -> i32 {
-    use std::cmp::Ordering;
-    let s = mu.floor() as i32;
-    let r = mu - s as f64;
-    let dss = 1.0 / (2.0 * sigma * sigma);
+pub fn samplerz<R: RngCore>(mu: Real, sigma: Real, sigmin: Real, rng: &mut R) -> i32 {
+    let s = mu.floor_int() as i32;
+    let r = mu - Real::of_i64(s as i64);
+    let dss = Real::of_f64(1.0) / (Real::of_f64(2.0) * sigma * sigma);
     let ccs = sigmin / sigma;
     loop {
         let z0 = basesampler(rng) as i32;
         let mut buf = [0u8; 1];
         rng.fill_bytes(&mut buf);
-        let mut b = buf[0] & 1;
+        let b = buf[0] & 1;
         let z = b as i32 + (2 * b as i32 - 1) * z0;
-        let mut x = ((z as f64 - r).powi(2)) * dss;
-        x -= (z0 as f64).powi(2) * INV_2SIGMA2;
+        let zr = Real::of_i64(z as i64) - r;
+        let z0 = Real::of_i64(z0 as i64);
+        let x = zr * zr * dss - z0 * z0 * Real::of_f64(INV_2SIGMA2);
         if berexp(x, ccs, rng) {
             return z + s;
         }
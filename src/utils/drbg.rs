@@ -0,0 +1,186 @@
+// NIST PQC bilinen yanıt testlerinin AES-256 CTR_DRBG üreteci (rng.c'den çevrildi)
+
+use rand::RngCore;
+
+/// AES S-box, built from the inverse in GF(2^8) followed by the affine map
+const SBOX: [u8; 256] = {
+    let mut sbox = [0u8; 256];
+    let (mut p, mut q) = (1u8, 1u8);
+    loop {
+        // p = p * 3
+        p ^= (p << 1) ^ if p & 0x80 != 0 { 0x1B } else { 0 };
+        // q = q / 3
+        q ^= q << 1;
+        q ^= q << 2;
+        q ^= q << 4;
+        if q & 0x80 != 0 {
+            q ^= 0x09;
+        }
+        let x = q ^ q.rotate_left(1) ^ q.rotate_left(2) ^ q.rotate_left(3) ^ q.rotate_left(4);
+        sbox[p as usize] = x ^ 0x63;
+        if p == 1 {
+            break;
+        }
+    }
+    sbox[0] = 0x63;
+    sbox
+};
+
+fn xtime(b: u8) -> u8 {
+    (b << 1) ^ if b & 0x80 != 0 { 0x1B } else { 0 }
+}
+
+/// AES-256 block cipher (encryption only)
+#[derive(Clone, Debug)]
+pub struct Aes256 {
+    round_keys: [[u8; 16]; 15],
+}
+
+impl Aes256 {
+    pub fn new(key: &[u8; 32]) -> Self {
+        let mut w = [[0u8; 4]; 60];
+        for i in 0..8 {
+            w[i].copy_from_slice(&key[4 * i..4 * i + 4]);
+        }
+        let mut rcon = 1u8;
+        for i in 8..60 {
+            let mut t = w[i - 1];
+            if i % 8 == 0 {
+                t = [SBOX[t[1] as usize] ^ rcon, SBOX[t[2] as usize], SBOX[t[3] as usize], SBOX[t[0] as usize]];
+                rcon = xtime(rcon);
+            } else if i % 8 == 4 {
+                t = t.map(|b| SBOX[b as usize]);
+            }
+            for j in 0..4 {
+                w[i][j] = w[i - 8][j] ^ t[j];
+            }
+        }
+        let mut round_keys = [[0u8; 16]; 15];
+        for (r, rk) in round_keys.iter_mut().enumerate() {
+            for c in 0..4 {
+                rk[4 * c..4 * c + 4].copy_from_slice(&w[4 * r + c]);
+            }
+        }
+        Self { round_keys }
+    }
+
+    /// Encrypts one 16-byte block
+    pub fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        let mut s = *block;
+        for (b, k) in s.iter_mut().zip(self.round_keys[0].iter()) {
+            *b ^= k;
+        }
+        for round in 1..15 {
+            // SubBytes and ShiftRows (the state is stored column by column)
+            let mut t = [0u8; 16];
+            for c in 0..4 {
+                for r in 0..4 {
+                    t[4 * c + r] = SBOX[s[4 * ((c + r) % 4) + r] as usize];
+                }
+            }
+            // MixColumns, skipped in the last round
+            if round < 14 {
+                for c in 0..4 {
+                    let a = [t[4 * c], t[4 * c + 1], t[4 * c + 2], t[4 * c + 3]];
+                    let all = a[0] ^ a[1] ^ a[2] ^ a[3];
+                    for r in 0..4 {
+                        t[4 * c + r] = a[r] ^ all ^ xtime(a[r] ^ a[(r + 1) % 4]);
+                    }
+                }
+            }
+            for (b, (x, k)) in s.iter_mut().zip(t.iter().zip(self.round_keys[round].iter())) {
+                *b = x ^ k;
+            }
+        }
+        s
+    }
+}
+
+/// The AES-256 CTR_DRBG of the NIST PQC `randombytes_init`/`randombytes`
+///
+/// Each call to `fill_bytes` is one call to `randombytes`: the key and V are
+/// updated after every request, so the output depends on how it is split
+/// into calls.
+#[derive(Clone, Debug)]
+pub struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl CtrDrbg {
+    /// `randombytes_init(entropy_input, NULL, 256)`
+    pub fn new(entropy_input: &[u8; 48]) -> Self {
+        Self::with_personalization(entropy_input, None)
+    }
+
+    /// `randombytes_init(entropy_input, personalization_string, 256)`
+    pub fn with_personalization(entropy_input: &[u8; 48], personalization: Option<&[u8; 48]>) -> Self {
+        let mut seed_material = *entropy_input;
+        if let Some(p) = personalization {
+            for (s, x) in seed_material.iter_mut().zip(p.iter()) {
+                *s ^= x;
+            }
+        }
+        let mut drbg = Self {
+            key: [0u8; 32],
+            v: [0u8; 16],
+        };
+        drbg.update(Some(&seed_material));
+        drbg
+    }
+
+    fn increment_v(&mut self) {
+        for b in self.v.iter_mut().rev() {
+            *b = b.wrapping_add(1);
+            if *b != 0 {
+                break;
+            }
+        }
+    }
+
+    /// AES256_CTR_DRBG_Update
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let aes = Aes256::new(&self.key);
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_exact_mut(16) {
+            self.increment_v();
+            chunk.copy_from_slice(&aes.encrypt_block(&self.v));
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data.iter()) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    /// `randombytes(x, xlen)`
+    pub fn randombytes(&mut self, x: &mut [u8]) {
+        let aes = Aes256::new(&self.key);
+        for chunk in x.chunks_mut(16) {
+            self.increment_v();
+            let block = aes.encrypt_block(&self.v);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+}
+
+impl RngCore for CtrDrbg {
+    fn next_u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.randombytes(&mut b);
+        u32::from_le_bytes(b)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.randombytes(&mut b);
+        u64::from_le_bytes(b)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.randombytes(dst);
+    }
+}
@@ -3,11 +3,22 @@
 //! This module contains utility functions including:
 //! - Common mathematical operations
 //! - Random number generation
+//! - SHAKE and SHA-3 (Keccak) hash functions
+//! - SHA-2 hash functions for pre-hashed signatures
+//! - NIST AES-256 CTR_DRBG for known-answer tests (`kat` feature)
 //! - Polynomial operations
 
 pub mod common;
 pub mod rng;
+pub mod shake;
+pub mod sha2;
+#[cfg(any(test, feature = "kat"))]
+pub mod drbg;
 
 // Re-export commonly used items
 pub use common::*;
-pub use rng::*; 
\ No newline at end of file
+pub use rng::*;
+pub use shake::*;
+pub use sha2::*;
+#[cfg(any(test, feature = "kat"))]
+pub use drbg::*; 
\ No newline at end of file
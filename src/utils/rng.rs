@@ -1,9 +1,11 @@
 // Falcon için ChaCha20 tabanlı PRNG (rng.py'den çevrildi)
 
+use rand::RngCore;
+
 const CW: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
 
 fn roll(x: u32, n: u32) -> u32 {
-    ((x << n) & 0xffffffff) | (x >> (32 - n))
+    x.rotate_left(n)
 }
 
 pub struct ChaCha20 {
@@ -56,7 +58,7 @@ impl ChaCha20 {
         }
         self.state[14] = self.s[10] ^ (self.ctr as u32);
         self.state[15] = self.s[11] ^ ((self.ctr >> 32) as u32);
-        let mut working_state = self.state;
+        let working_state = self.state;
         for _ in 0..10 {
             self.qround(0, 4, 8, 12);
             self.qround(1, 5, 9, 13);
@@ -67,8 +69,8 @@ impl ChaCha20 {
             self.qround(2, 7, 8, 13);
             self.qround(3, 4, 9, 14);
         }
-        for i in 0..16 {
-            self.state[i] = self.state[i].wrapping_add(working_state[i]);
+        for (s, w) in self.state.iter_mut().zip(working_state.iter()) {
+            *s = s.wrapping_add(*w);
         }
         self.ctr += 1;
         self.state
@@ -97,4 +99,48 @@ impl ChaCha20 {
         self.hexbytes = self.hexbytes[k..].to_vec();
         out
     }
-} 
\ No newline at end of file
+} 
+
+/// Random source replaying a fixed byte string, for known-answer tests
+///
+/// Each request is served the next bytes of the string in reverse order, as
+/// in the SamplerZ known-answer tests of the reference implementation, whose
+/// vectors write every random value big-endian. Running out of bytes is a bug
+/// in the test vector or in the code under test, so it panics.
+#[derive(Clone, Debug)]
+pub struct ReplayRng {
+    bytes: Vec<u8>,
+    pos: usize,
+}
+
+impl ReplayRng {
+    pub fn new(bytes: &[u8]) -> Self {
+        Self { bytes: bytes.to_vec(), pos: 0 }
+    }
+
+    /// Number of bytes not consumed yet
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+}
+
+impl RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.fill_bytes(&mut b);
+        u32::from_le_bytes(b)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.fill_bytes(&mut b);
+        u64::from_le_bytes(b)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        assert!(dst.len() <= self.remaining(), "replayed bytes exhausted");
+        dst.copy_from_slice(&self.bytes[self.pos..self.pos + dst.len()]);
+        dst.reverse();
+        self.pos += dst.len();
+    }
+}
@@ -0,0 +1,279 @@
+// Falcon için SHAKE256 genişletilebilir çıktı fonksiyonu ve SHA-3 özetleri (FIPS 202)
+
+use rand::RngCore;
+
+/// Keccak-f[1600] round constants
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets, indexed by lane (x + 5 * y)
+const RHO: [u32; 25] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+];
+
+/// Keccak-f[1600] permutation
+fn keccak_f(a: &mut [u64; 25]) {
+    for &rc in RC.iter() {
+        // θ
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+        // ρ and π
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(RHO[x + 5 * y]);
+            }
+        }
+        // χ
+        for x in 0..5 {
+            for y in 0..5 {
+                a[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+        // ι
+        a[0] ^= rc;
+    }
+}
+
+/// Keccak sponge with a given rate (in bytes) and domain separation suffix
+#[derive(Clone, Debug)]
+struct Sponge {
+    state: [u64; 25],
+    rate: usize,
+    suffix: u8,
+    pos: usize,
+    squeezing: bool,
+}
+
+impl Sponge {
+    fn new(rate: usize, suffix: u8) -> Self {
+        Self {
+            state: [0u64; 25],
+            rate,
+            suffix,
+            pos: 0,
+            squeezing: false,
+        }
+    }
+
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= (b as u64) << (8 * (i % 8));
+    }
+
+    fn get_byte(&self, i: usize) -> u8 {
+        (self.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        assert!(!self.squeezing, "Keccak context already finalized");
+        for &b in data {
+            self.xor_byte(self.pos, b);
+            self.pos += 1;
+            if self.pos == self.rate {
+                keccak_f(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    fn finalize(&mut self) {
+        if self.squeezing {
+            return;
+        }
+        self.xor_byte(self.pos, self.suffix);
+        self.xor_byte(self.rate - 1, 0x80);
+        keccak_f(&mut self.state);
+        self.pos = 0;
+        self.squeezing = true;
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        self.finalize();
+        for b in out.iter_mut() {
+            if self.pos == self.rate {
+                keccak_f(&mut self.state);
+                self.pos = 0;
+            }
+            *b = self.get_byte(self.pos);
+            self.pos += 1;
+        }
+    }
+}
+
+/// Incremental SHAKE256 context.
+///
+/// Data is absorbed with `update`, then `finalize` switches the context to
+/// squeezing mode and `squeeze` can be called any number of times to read
+/// consecutive output bytes.
+#[derive(Clone, Debug)]
+pub struct Shake256 {
+    sponge: Sponge,
+}
+
+impl Default for Shake256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shake256 {
+    pub fn new() -> Self {
+        // Rate of 1600 - 2 * 256 bits
+        Self { sponge: Sponge::new(136, 0x1F) }
+    }
+
+    /// Absorbs `data` into the context
+    pub fn update(&mut self, data: &[u8]) {
+        self.sponge.update(data);
+    }
+
+    /// Applies the SHAKE padding and switches to squeezing mode
+    pub fn finalize(&mut self) {
+        self.sponge.finalize();
+    }
+
+    /// Fills `out` with the next output bytes, finalizing the context if needed
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        self.sponge.squeeze(out);
+    }
+}
+
+/// A finalized SHAKE256 context is a deterministic random source, e.g. for
+/// deriving keys from a seed
+impl RngCore for Shake256 {
+    fn next_u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.squeeze(&mut b);
+        u32::from_le_bytes(b)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.squeeze(&mut b);
+        u64::from_le_bytes(b)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.squeeze(dst);
+    }
+}
+
+/// Incremental SHAKE128 context, used the same way as `Shake256`
+#[derive(Clone, Debug)]
+pub struct Shake128 {
+    sponge: Sponge,
+}
+
+impl Default for Shake128 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shake128 {
+    pub fn new() -> Self {
+        // Rate of 1600 - 2 * 128 bits
+        Self { sponge: Sponge::new(168, 0x1F) }
+    }
+
+    /// Absorbs `data` into the context
+    pub fn update(&mut self, data: &[u8]) {
+        self.sponge.update(data);
+    }
+
+    /// Fills `out` with the next output bytes, finalizing the context if needed
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        self.sponge.squeeze(out);
+    }
+}
+
+/// Incremental SHA3-256, SHA3-384 or SHA3-512 context
+#[derive(Clone, Debug)]
+pub struct Sha3 {
+    sponge: Sponge,
+    outlen: usize,
+}
+
+impl Sha3 {
+    /// Starts a SHA-3 computation with a digest of `outlen` bytes (32, 48 or 64)
+    pub fn new(outlen: usize) -> Self {
+        assert!(matches!(outlen, 32 | 48 | 64), "unsupported SHA-3 digest length");
+        Self {
+            sponge: Sponge::new(200 - 2 * outlen, 0x06),
+            outlen,
+        }
+    }
+
+    /// Absorbs `data` into the context
+    pub fn update(&mut self, data: &[u8]) {
+        self.sponge.update(data);
+    }
+
+    /// Returns the digest
+    pub fn finalize(mut self) -> Vec<u8> {
+        let mut out = vec![0u8; self.outlen];
+        self.sponge.squeeze(&mut out);
+        out
+    }
+}
+
+/// One-shot SHAKE256 of `data` with an output of `outlen` bytes
+pub fn shake256(data: &[u8], outlen: usize) -> Vec<u8> {
+    let mut ctx = Shake256::new();
+    ctx.update(data);
+    let mut out = vec![0u8; outlen];
+    ctx.squeeze(&mut out);
+    out
+}
+
+/// One-shot SHAKE128 of `data` with an output of `outlen` bytes
+pub fn shake128(data: &[u8], outlen: usize) -> Vec<u8> {
+    let mut ctx = Shake128::new();
+    ctx.update(data);
+    let mut out = vec![0u8; outlen];
+    ctx.squeeze(&mut out);
+    out
+}
+
+/// One-shot SHA3 of `data` with a digest of `outlen` bytes (32, 48 or 64)
+pub fn sha3(data: &[u8], outlen: usize) -> Vec<u8> {
+    let mut ctx = Sha3::new(outlen);
+    ctx.update(data);
+    ctx.finalize()
+}